@@ -11,7 +11,7 @@ use std::collections::HashMap;
 use subxt_codegen::fetch_metadata::MetadataVersion;
 use subxt_metadata::Metadata;
 
-use crate::utils::validate_url_security;
+use crate::utils::{validate_url_security, OutputFormat};
 
 /// Verify metadata compatibility between substrate nodes.
 #[derive(Debug, ClapParser)]
@@ -41,6 +41,9 @@ pub struct Opts {
     /// Allow insecure URLs e.g. URLs starting with ws:// or http:// without SSL encryption
     #[clap(long, short)]
     allow_insecure: bool,
+    /// The output format. With `json`, only the map of compatible nodes is printed.
+    #[clap(long, value_enum, default_value_t = OutputFormat::Plain)]
+    output: OutputFormat,
 }
 
 pub async fn run(opts: Opts, output: &mut impl std::io::Write) -> color_eyre::Result<()> {
@@ -50,10 +53,18 @@ pub async fn run(opts: Opts, output: &mut impl std::io::Write) -> color_eyre::Re
 
     match opts.pallet {
         Some(pallet) => {
-            handle_pallet_metadata(opts.nodes.as_slice(), pallet.as_str(), opts.version, output)
-                .await
+            handle_pallet_metadata(
+                opts.nodes.as_slice(),
+                pallet.as_str(),
+                opts.version,
+                opts.output,
+                output,
+            )
+            .await
+        }
+        None => {
+            handle_full_metadata(opts.nodes.as_slice(), opts.version, opts.output, output).await
         }
-        None => handle_full_metadata(opts.nodes.as_slice(), opts.version, output).await,
     }
 }
 
@@ -61,6 +72,7 @@ async fn handle_pallet_metadata(
     nodes: &[Url],
     name: &str,
     version: MetadataVersion,
+    format: OutputFormat,
     output: &mut impl std::io::Write,
 ) -> color_eyre::Result<()> {
     #[derive(Serialize, Deserialize, Default)]
@@ -78,10 +90,12 @@ async fn handle_pallet_metadata(
             Some(pallet_metadata) => {
                 let hash = pallet_metadata.hash();
                 let hex_hash = hex::encode(hash);
-                writeln!(
-                    output,
-                    "Node {node:?} has pallet metadata hash {hex_hash:?}"
-                )?;
+                if format == OutputFormat::Plain {
+                    writeln!(
+                        output,
+                        "Node {node:?} has pallet metadata hash {hex_hash:?}"
+                    )?;
+                }
 
                 compatibility
                     .pallet_present
@@ -95,12 +109,12 @@ async fn handle_pallet_metadata(
         }
     }
 
-    writeln!(
-        output,
-        "\nCompatible nodes by pallet\n{}",
-        serde_json::to_string_pretty(&compatibility)
-            .context("Failed to parse compatibility map")?
-    )?;
+    let json = serde_json::to_string_pretty(&compatibility)
+        .context("Failed to parse compatibility map")?;
+    match format {
+        OutputFormat::Plain => writeln!(output, "\nCompatible nodes by pallet\n{json}")?,
+        OutputFormat::Json => writeln!(output, "{json}")?,
+    }
 
     Ok(())
 }
@@ -108,6 +122,7 @@ async fn handle_pallet_metadata(
 async fn handle_full_metadata(
     nodes: &[Url],
     version: MetadataVersion,
+    format: OutputFormat,
     output: &mut impl std::io::Write,
 ) -> color_eyre::Result<()> {
     let mut compatibility_map: HashMap<String, Vec<String>> = HashMap::new();
@@ -115,7 +130,9 @@ async fn handle_full_metadata(
         let metadata = fetch_runtime_metadata(node.clone(), version).await?;
         let hash = metadata.hasher().hash();
         let hex_hash = hex::encode(hash);
-        writeln!(output, "Node {node:?} has metadata hash {hex_hash:?}",)?;
+        if format == OutputFormat::Plain {
+            writeln!(output, "Node {node:?} has metadata hash {hex_hash:?}",)?;
+        }
 
         compatibility_map
             .entry(hex_hash)
@@ -123,12 +140,12 @@ async fn handle_full_metadata(
             .push(node.to_string());
     }
 
-    writeln!(
-        output,
-        "\nCompatible nodes\n{}",
-        serde_json::to_string_pretty(&compatibility_map)
-            .context("Failed to parse compatibility map")?
-    )?;
+    let json = serde_json::to_string_pretty(&compatibility_map)
+        .context("Failed to parse compatibility map")?;
+    match format {
+        OutputFormat::Plain => writeln!(output, "\nCompatible nodes\n{json}")?,
+        OutputFormat::Json => writeln!(output, "{json}")?,
+    }
 
     Ok(())
 }
@@ -5,7 +5,7 @@ use frame_metadata::RuntimeMetadataPrefixed;
 use std::collections::HashMap;
 use std::hash::Hash;
 
-use crate::utils::{validate_url_security, FileOrUrl};
+use crate::utils::{validate_url_security, FileOrUrl, OutputFormat};
 use color_eyre::owo_colors::OwoColorize;
 
 use scale_info::form::PortableForm;
@@ -32,6 +32,10 @@ pub struct Opts {
     /// Allow insecure URLs e.g. URLs starting with ws:// or http:// without SSL encryption
     #[clap(long, short)]
     allow_insecure: bool,
+    /// The output format. With `json`, the differences are printed as a JSON document
+    /// listing the pallets and runtime APIs which were added, removed or changed.
+    #[clap(long, value_enum, default_value_t = OutputFormat::Plain)]
+    output: OutputFormat,
 }
 
 pub async fn run(opts: Opts, output: &mut impl std::io::Write) -> color_eyre::Result<()> {
@@ -42,6 +46,12 @@ pub async fn run(opts: Opts, output: &mut impl std::io::Write) -> color_eyre::Re
 
     let metadata_diff = MetadataDiff::construct(&entry_1_metadata, &entry_2_metadata);
 
+    if opts.output == OutputFormat::Json {
+        let json = metadata_diff.to_json(&entry_1_metadata, &entry_2_metadata);
+        writeln!(output, "{}", serde_json::to_string_pretty(&json)?)?;
+        return Ok(());
+    }
+
     if metadata_diff.is_empty() {
         writeln!(output, "No difference in metadata found.")?;
         return Ok(());
@@ -186,6 +196,54 @@ impl<'a> MetadataDiff<'a> {
     fn is_empty(&self) -> bool {
         self.pallets.is_empty() && self.runtime_apis.is_empty()
     }
+
+    /// Describe the differences as a JSON document, for use in scripts.
+    fn to_json(&self, metadata_1: &Metadata, metadata_2: &Metadata) -> serde_json::Value {
+        let pallets: Vec<_> = self
+            .pallets
+            .iter()
+            .map(|diff| {
+                let mut json = diff.to_json(|p| p.name().to_owned());
+                if let Diff::Changed { from, to } = diff {
+                    let pallet_diff = PalletDiff::construct(from, to);
+                    json["calls"] = pallet_diff
+                        .calls
+                        .iter()
+                        .map(|diff| diff.to_json(|c| c.name.clone()))
+                        .collect();
+                    json["constants"] = pallet_diff
+                        .constants
+                        .iter()
+                        .map(|diff| diff.to_json(|c| c.name().to_owned()))
+                        .collect();
+                    json["storageEntries"] = pallet_diff
+                        .storage_entries
+                        .iter()
+                        .map(|diff| {
+                            let mut json = diff.to_json(|e| e.name().to_owned());
+                            if let Diff::Changed { from, to } = diff {
+                                let storage_diff =
+                                    StorageEntryDiff::construct(from, to, metadata_1, metadata_2);
+                                json["differences"] = storage_diff.to_strings().into();
+                            }
+                            json
+                        })
+                        .collect();
+                }
+                json
+            })
+            .collect();
+        let runtime_apis: Vec<_> = self
+            .runtime_apis
+            .iter()
+            .map(|diff| diff.to_json(|api| api.name().to_owned()))
+            .collect();
+
+        serde_json::json!({
+            "pallets": pallets,
+            "runtimeApis": runtime_apis,
+        })
+    }
 }
 
 #[derive(Default)]
@@ -395,6 +453,21 @@ enum Diff<T> {
     Removed(T),
 }
 
+impl<T> Diff<T> {
+    /// A JSON object with the name of the item and how it changed.
+    fn to_json(&self, name: impl Fn(&T) -> String) -> serde_json::Value {
+        let (change, item) = match self {
+            Diff::Added(new) => ("added", new),
+            Diff::Removed(old) => ("removed", old),
+            Diff::Changed { from, to: _ } => ("changed", from),
+        };
+        serde_json::json!({
+            "name": name(item),
+            "change": change,
+        })
+    }
+}
+
 fn diff<T, C: PartialEq, I: Hash + PartialEq + Eq + Ord>(
     items_a: impl IntoIterator<Item = T>,
     items_b: impl IntoIterator<Item = T>,
@@ -441,7 +514,85 @@ fn diff<T, C: PartialEq, I: Hash + PartialEq + Eq + Ord>(
 
 #[cfg(test)]
 mod test {
-    use crate::commands::diff::{diff, Diff};
+    use crate::commands::diff::{diff, Diff, Opts};
+    use serde_json::json;
+
+    #[derive(clap::Parser)]
+    struct Cli {
+        #[command(flatten)]
+        opts: Opts,
+    }
+
+    async fn run_json(file_1: &str, file_2: &str) -> serde_json::Value {
+        let cli: Cli =
+            clap::Parser::try_parse_from(["diff", file_1, file_2, "--output", "json"]).unwrap();
+        let mut output: Vec<u8> = Vec::new();
+        super::run(cli.opts, &mut output).await.unwrap();
+        serde_json::from_slice(&output).unwrap()
+    }
+
+    #[tokio::test]
+    async fn json_output() {
+        let small = "../artifacts/polkadot_metadata_small.scale";
+        let tiny = "../artifacts/polkadot_metadata_tiny.scale";
+
+        // No differences means empty lists rather than a message:
+        let output = run_json(small, small).await;
+        assert_eq!(output, json!({ "pallets": [], "runtimeApis": [] }));
+
+        let output = run_json(small, tiny).await;
+        let keys: Vec<_> = output.as_object().unwrap().keys().collect();
+        assert_eq!(keys, ["pallets", "runtimeApis"]);
+
+        let pallets = output["pallets"].as_array().unwrap();
+        assert!(!pallets.is_empty());
+        for pallet in pallets {
+            assert!(pallet["name"].is_string());
+            match pallet["change"].as_str().unwrap() {
+                "added" | "removed" => assert_eq!(pallet.as_object().unwrap().len(), 2),
+                "changed" => {
+                    for key in ["calls", "constants", "storageEntries"] {
+                        for item in pallet[key].as_array().unwrap() {
+                            assert!(item["name"].is_string());
+                            assert!(item["change"].is_string());
+                        }
+                    }
+                    // Changed storage entries also list what about them changed:
+                    for item in pallet["storageEntries"].as_array().unwrap() {
+                        if item["change"] == "changed" {
+                            assert!(item["differences"].is_array());
+                        }
+                    }
+                }
+                change => panic!("unexpected change {change}"),
+            }
+        }
+        for api in output["runtimeApis"].as_array().unwrap() {
+            assert_eq!(api.as_object().unwrap().len(), 2);
+            assert!(api["name"].is_string());
+            assert!(api["change"].is_string());
+        }
+    }
+
+    #[test]
+    fn diff_to_json() {
+        let storage_entry: Diff<&str> = Diff::Changed {
+            from: "Account",
+            to: "Account",
+        };
+        assert_eq!(
+            storage_entry.to_json(|e| e.to_string()),
+            json!({ "name": "Account", "change": "changed" })
+        );
+        assert_eq!(
+            Diff::Added("Nfts").to_json(|e| e.to_string()),
+            json!({ "name": "Nfts", "change": "added" })
+        );
+        assert_eq!(
+            Diff::Removed("Babe").to_json(|e| e.to_string()),
+            json!({ "name": "Babe", "change": "removed" })
+        );
+    }
 
     #[test]
     fn test_diff_fn() {
@@ -1,5 +1,5 @@
 use crate::utils::validate_url_security;
-use crate::utils::FileOrUrl;
+use crate::utils::{FileOrUrl, OutputFormat};
 use clap::{command, Parser, Subcommand};
 use codec::Decode;
 use color_eyre::eyre::eyre;
@@ -111,6 +111,11 @@ pub struct Opts {
     /// Allow insecure URLs e.g. URLs starting with ws:// or http:// without SSL encryption
     #[clap(long, short)]
     allow_insecure: bool,
+    /// The output format used when listing the available pallets and runtime APIs. With
+    /// `json`, only the names are printed, as a JSON document. Exploring a specific pallet
+    /// or runtime API only supports `plain` output, and fails if `json` is asked for.
+    #[clap(long, value_enum, default_value_t = OutputFormat::Plain)]
+    output: OutputFormat,
 }
 
 #[derive(Debug, Subcommand)]
@@ -150,6 +155,14 @@ pub async fn run(opts: Opts, output: &mut impl std::io::Write) -> color_eyre::Re
 
     // if no pallet/runtime_api specified, show user the pallets/runtime_apis to choose from:
     let Some(pallet_or_runtime_api) = opts.subcommand else {
+        if opts.output == OutputFormat::Json {
+            let json = serde_json::json!({
+                "pallets": pallet_names(&metadata),
+                "runtimeApis": runtime_api_names(&metadata),
+            });
+            writeln!(output, "{}", serde_json::to_string_pretty(&json)?)?;
+            return Ok(());
+        }
         let pallets = pallets_as_string(&metadata);
         let runtime_apis = runtime_apis_as_string(&metadata);
         writedoc! {output, "
@@ -166,9 +179,24 @@ pub async fn run(opts: Opts, output: &mut impl std::io::Write) -> color_eyre::Re
         return Ok(());
     };
 
+    let output_format = opts.output;
+    let ensure_plain_output = || {
+        if output_format == OutputFormat::Json {
+            Err(eyre!(
+                "`--output json` is only supported when listing pallets or runtime APIs"
+            ))
+        } else {
+            Ok(())
+        }
+    };
     match pallet_or_runtime_api {
         PalletOrRuntimeApi::Pallet(opts) => {
             let Some(name) = opts.name else {
+                if output_format == OutputFormat::Json {
+                    let json = serde_json::json!({ "pallets": pallet_names(&metadata) });
+                    writeln!(output, "{}", serde_json::to_string_pretty(&json)?)?;
+                    return Ok(());
+                }
                 let pallets = pallets_as_string(&metadata);
                 writedoc! {output, "
                 Usage:
@@ -180,6 +208,7 @@ pub async fn run(opts: Opts, output: &mut impl std::io::Write) -> color_eyre::Re
                 return Ok(());
             };
 
+            ensure_plain_output()?;
             if let Some(pallet) = metadata
                 .pallets()
                 .find(|e| e.name().eq_ignore_ascii_case(&name))
@@ -194,6 +223,11 @@ pub async fn run(opts: Opts, output: &mut impl std::io::Write) -> color_eyre::Re
         }
         PalletOrRuntimeApi::Api(opts) => {
             let Some(name) = opts.name else {
+                if output_format == OutputFormat::Json {
+                    let json = serde_json::json!({ "runtimeApis": runtime_api_names(&metadata) });
+                    writeln!(output, "{}", serde_json::to_string_pretty(&json)?)?;
+                    return Ok(());
+                }
                 let runtime_apis = runtime_apis_as_string(&metadata);
                 writedoc! {output, "
                 Usage:
@@ -205,6 +239,7 @@ pub async fn run(opts: Opts, output: &mut impl std::io::Write) -> color_eyre::Re
                 return Ok(());
             };
 
+            ensure_plain_output()?;
             if let Some(runtime_api) = metadata
                 .runtime_api_traits()
                 .find(|e| e.name().eq_ignore_ascii_case(&name))
@@ -235,9 +270,7 @@ fn pallets_as_string(metadata: &Metadata) -> String {
         format!("There are no {pallet_placeholder}'s available.")
     } else {
         let mut output = format!("Available {pallet_placeholder}'s are:");
-        let mut strings: Vec<_> = metadata.pallets().map(|p| p.name()).collect();
-        strings.sort();
-        for pallet in strings {
+        for pallet in pallet_names(metadata) {
             write!(output, "\n    {}", pallet).unwrap();
         }
         output
@@ -250,20 +283,33 @@ pub fn runtime_apis_as_string(metadata: &Metadata) -> String {
         format!("There are no {runtime_api_placeholder}'s available.")
     } else {
         let mut output = format!("Available {runtime_api_placeholder}'s are:");
-        let mut strings: Vec<_> = metadata.runtime_api_traits().map(|p| p.name()).collect();
-        strings.sort();
-        for api in strings {
+        for api in runtime_api_names(metadata) {
             write!(output, "\n    {}", api).unwrap();
         }
         output
     }
 }
 
+/// The names of the pallets in the metadata, sorted alphabetically.
+fn pallet_names(metadata: &Metadata) -> Vec<&str> {
+    let mut names: Vec<_> = metadata.pallets().map(|p| p.name()).collect();
+    names.sort();
+    names
+}
+
+/// The names of the runtime APIs in the metadata, sorted alphabetically.
+fn runtime_api_names(metadata: &Metadata) -> Vec<&str> {
+    let mut names: Vec<_> = metadata.runtime_api_traits().map(|p| p.name()).collect();
+    names.sort();
+    names
+}
+
 #[cfg(test)]
 pub mod tests {
 
     use indoc::formatdoc;
     use pretty_assertions::assert_eq;
+    use serde_json::json;
 
     use super::Opts;
 
@@ -437,6 +483,62 @@ pub mod tests {
         assert_eq_start!(output, start);
     }
 
+    #[tokio::test]
+    async fn json_listings() {
+        let pallets = json!([
+            "Balances",
+            "Multisig",
+            "ParaInherent",
+            "System",
+            "Timestamp"
+        ]);
+        let runtime_apis = json!([
+            "AccountNonceApi",
+            "AuthorityDiscoveryApi",
+            "BabeApi",
+            "BeefyApi",
+            "BeefyMmrApi",
+            "BlockBuilder",
+            "Core",
+            "GenesisBuilder",
+            "GrandpaApi",
+            "Metadata",
+            "MmrApi",
+            "OffchainWorkerApi",
+            "ParachainHost",
+            "SessionKeys",
+            "TaggedTransactionQueue",
+            "TransactionPaymentApi"
+        ]);
+
+        let run_json = |cli_command: &'static str| async move {
+            let output = run_against_file(cli_command).await.unwrap();
+            serde_json::from_str::<serde_json::Value>(&output).unwrap()
+        };
+
+        assert_eq!(
+            run_json("--output json").await,
+            json!({ "pallets": pallets, "runtimeApis": runtime_apis })
+        );
+        assert_eq!(
+            run_json("--output json pallet").await,
+            json!({ "pallets": pallets })
+        );
+        assert_eq!(
+            run_json("--output json api").await,
+            json!({ "runtimeApis": runtime_apis })
+        );
+
+        // Exploring a specific pallet or runtime API has no JSON output, so rather than
+        // ignore the flag, we fail:
+        for cmd in ["pallet Balances calls", "api Core version"] {
+            let err = run_against_file(&format!("--output json {cmd}"))
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("only supported when listing"));
+        }
+    }
+
     #[tokio::test]
     async fn insecure_urls_get_denied() {
         // Connection should work fine:
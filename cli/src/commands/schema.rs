@@ -0,0 +1,305 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::utils::{validate_url_security, FileOrUrl};
+use clap::Parser as ClapParser;
+use codec::Decode;
+use color_eyre::eyre::eyre;
+use frame_metadata::RuntimeMetadataPrefixed;
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef, TypeDefPrimitive};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::{io::Write, path::PathBuf};
+use subxt_metadata::Metadata;
+
+/// Export a JSON Schema describing the events and calls in some metadata, as they are
+/// represented when subxt serializes them to JSON (via `scale_value`).
+///
+/// Each event and call schema is found at `#/events/<Pallet>/<Name>` or
+/// `#/calls/<Pallet>/<Name>` respectively, and describes its fields. Types shared between
+/// them live in `#/$defs`, keyed by their type ID.
+///
+/// # Example
+/// ```
+/// subxt schema --file ./artifacts/polkadot_metadata_small.scale --pallets Balances
+/// ```
+#[derive(Debug, ClapParser)]
+pub struct Opts {
+    #[command(flatten)]
+    file_or_url: FileOrUrl,
+    /// Only export schemas for the given pallets.
+    #[clap(long, use_value_delimiter = true, value_parser)]
+    pallets: Option<Vec<String>>,
+    /// Write the schema to the provided file path.
+    #[clap(long, short, value_parser)]
+    output_file: Option<PathBuf>,
+    /// Allow insecure URLs e.g. URLs starting with ws:// or http:// without SSL encryption
+    #[clap(long, short)]
+    allow_insecure: bool,
+}
+
+pub async fn run(opts: Opts, output: &mut impl Write) -> color_eyre::Result<()> {
+    validate_url_security(opts.file_or_url.url.as_ref(), opts.allow_insecure)?;
+    let bytes = opts.file_or_url.fetch().await?;
+    let metadata: Metadata = RuntimeMetadataPrefixed::decode(&mut &bytes[..])?.try_into()?;
+
+    if let Some(pallets) = &opts.pallets {
+        if let Some(missing) = pallets
+            .iter()
+            .find(|p| metadata.pallet_by_name(p).is_none())
+        {
+            return Err(eyre!("Pallet {missing} not found in the metadata"));
+        }
+    }
+    let include_pallet = |name: &str| match &opts.pallets {
+        Some(pallets) => pallets.iter().any(|p| p == name),
+        None => true,
+    };
+
+    let mut builder = SchemaBuilder::new(metadata.types());
+    let mut events = Map::new();
+    let mut calls = Map::new();
+    for pallet in metadata.pallets().filter(|p| include_pallet(p.name())) {
+        if let Some(variants) = pallet.event_variants() {
+            let schemas = variants
+                .iter()
+                .map(|v| (v.name.clone(), builder.fields_schema(&v.fields)))
+                .collect();
+            events.insert(pallet.name().to_string(), Value::Object(schemas));
+        }
+        if let Some(variants) = pallet.call_variants() {
+            let schemas = variants
+                .iter()
+                .map(|v| (v.name.clone(), builder.fields_schema(&v.fields)))
+                .collect();
+            calls.insert(pallet.name().to_string(), Value::Object(schemas));
+        }
+    }
+
+    let schema = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$defs": builder.into_defs(),
+        "events": events,
+        "calls": calls,
+    });
+
+    let mut output: Box<dyn Write> = match opts.output_file {
+        Some(path) => Box::new(std::fs::File::create(path)?),
+        None => Box::new(output),
+    };
+    writeln!(output, "{}", serde_json::to_string_pretty(&schema)?)?;
+    Ok(())
+}
+
+/// Builds schemas for fields, collecting the schema of every type that they refer to.
+struct SchemaBuilder<'a> {
+    types: &'a PortableRegistry,
+    defs: BTreeMap<u32, Value>,
+    pending: BTreeSet<u32>,
+}
+
+impl<'a> SchemaBuilder<'a> {
+    fn new(types: &'a PortableRegistry) -> Self {
+        SchemaBuilder {
+            types,
+            defs: BTreeMap::new(),
+            pending: BTreeSet::new(),
+        }
+    }
+
+    /// A reference to the schema for some type, which will be added to the `$defs`.
+    fn type_ref(&mut self, type_id: u32) -> Value {
+        if !self.defs.contains_key(&type_id) {
+            self.pending.insert(type_id);
+        }
+        json!({ "$ref": format!("#/$defs/{type_id}") })
+    }
+
+    /// The schema for a set of fields. Named fields are serialized as an object, and
+    /// unnamed fields as an array.
+    fn fields_schema(&mut self, fields: &[Field<PortableForm>]) -> Value {
+        if fields.is_empty() {
+            return json!({
+                "anyOf": [
+                    { "type": "array", "maxItems": 0 },
+                    { "type": "object", "maxProperties": 0 },
+                ]
+            });
+        }
+
+        if fields.iter().all(|f| f.name.is_some()) {
+            let mut properties = Map::new();
+            let mut required = Vec::new();
+            for field in fields {
+                let name = field.name.clone().expect("all fields are named; qed");
+                properties.insert(name.clone(), self.type_ref(field.ty.id));
+                required.push(Value::String(name));
+            }
+            json!({
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false,
+            })
+        } else {
+            let items: Vec<_> = fields.iter().map(|f| self.type_ref(f.ty.id)).collect();
+            tuple_schema(items)
+        }
+    }
+
+    /// The schema for a single type.
+    fn type_schema(&mut self, type_id: u32) -> Value {
+        let types = self.types;
+        let Some(ty) = types.resolve(type_id) else {
+            // Accept anything rather than failing on metadata we can't fully resolve.
+            return json!({});
+        };
+
+        match &ty.type_def {
+            TypeDef::Composite(composite) => self.fields_schema(&composite.fields),
+            TypeDef::Variant(variant) => {
+                let variants: Vec<_> = variant
+                    .variants
+                    .iter()
+                    .map(|v| {
+                        json!({
+                            "type": "object",
+                            "properties": {
+                                "name": { "const": v.name },
+                                "values": self.fields_schema(&v.fields),
+                            },
+                            "required": ["name", "values"],
+                            "additionalProperties": false,
+                        })
+                    })
+                    .collect();
+                json!({ "oneOf": variants })
+            }
+            TypeDef::Sequence(seq) => json!({
+                "type": "array",
+                "items": self.type_ref(seq.type_param.id),
+            }),
+            TypeDef::Array(arr) => json!({
+                "type": "array",
+                "items": self.type_ref(arr.type_param.id),
+                "minItems": arr.len,
+                "maxItems": arr.len,
+            }),
+            TypeDef::Tuple(tuple) => {
+                let items: Vec<_> = tuple.fields.iter().map(|f| self.type_ref(f.id)).collect();
+                tuple_schema(items)
+            }
+            TypeDef::Primitive(primitive) => primitive_schema(primitive),
+            TypeDef::Compact(compact) => self.type_ref(compact.type_param.id),
+            TypeDef::BitSequence(_) => json!({
+                "type": "array",
+                "items": { "type": "boolean" },
+            }),
+        }
+    }
+
+    /// Build the schema of every type that's been referred to, returning them all.
+    fn into_defs(mut self) -> Map<String, Value> {
+        while let Some(type_id) = self.pending.pop_first() {
+            // Insert a placeholder first, in case the type refers to itself.
+            self.defs.insert(type_id, Value::Null);
+            let schema = self.type_schema(type_id);
+            self.defs.insert(type_id, schema);
+        }
+        self.defs
+            .into_iter()
+            .map(|(id, schema)| (id.to_string(), schema))
+            .collect()
+    }
+}
+
+fn tuple_schema(items: Vec<Value>) -> Value {
+    let len = items.len();
+    json!({
+        "type": "array",
+        "prefixItems": items,
+        "items": false,
+        "minItems": len,
+    })
+}
+
+fn primitive_schema(primitive: &TypeDefPrimitive) -> Value {
+    let integer = |min: i64, max: u64| json!({ "type": "integer", "minimum": min, "maximum": max });
+    match primitive {
+        TypeDefPrimitive::Bool => json!({ "type": "boolean" }),
+        TypeDefPrimitive::Char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        TypeDefPrimitive::Str => json!({ "type": "string" }),
+        TypeDefPrimitive::U8 => integer(0, u8::MAX.into()),
+        TypeDefPrimitive::U16 => integer(0, u16::MAX.into()),
+        TypeDefPrimitive::U32 => integer(0, u32::MAX.into()),
+        TypeDefPrimitive::U64 => integer(0, u64::MAX),
+        TypeDefPrimitive::I8 => integer(i8::MIN.into(), i8::MAX as u64),
+        TypeDefPrimitive::I16 => integer(i16::MIN.into(), i16::MAX as u64),
+        TypeDefPrimitive::I32 => integer(i32::MIN.into(), i32::MAX as u64),
+        TypeDefPrimitive::I64 => integer(i64::MIN, i64::MAX as u64),
+        // The bounds of 128 bit numbers can't be represented as JSON numbers without
+        // losing precision, so we leave them out.
+        TypeDefPrimitive::U128 => json!({ "type": "integer", "minimum": 0 }),
+        TypeDefPrimitive::I128 => json!({ "type": "integer" }),
+        // 256 bit numbers are serialized as their 32 little endian bytes.
+        TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => json!({
+            "type": "array",
+            "items": { "type": "integer", "minimum": 0, "maximum": 255 },
+            "minItems": 32,
+            "maxItems": 32,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Opts;
+
+    async fn run(cli_command: &str) -> color_eyre::Result<serde_json::Value> {
+        let mut args = vec![
+            "schema",
+            "--file=../artifacts/polkadot_metadata_small.scale",
+        ];
+        args.extend(cli_command.split(' ').filter(|e| !e.is_empty()));
+        let opts: Opts = clap::Parser::try_parse_from(args)?;
+        let mut output: Vec<u8> = Vec::new();
+        super::run(opts, &mut output).await?;
+        Ok(serde_json::from_slice(&output)?)
+    }
+
+    #[tokio::test]
+    async fn exports_event_and_call_schemas() {
+        let schema = run("--pallets Balances").await.unwrap();
+
+        // Only the Balances pallet is included:
+        let events = schema["events"].as_object().unwrap();
+        assert_eq!(events.keys().collect::<Vec<_>>(), vec!["Balances"]);
+
+        // Transfer has named fields which refer to types in the $defs:
+        let transfer = &schema["events"]["Balances"]["Transfer"];
+        assert_eq!(transfer["type"], "object");
+        assert_eq!(
+            transfer["required"],
+            serde_json::json!(["from", "to", "amount"])
+        );
+        let amount_ref = transfer["properties"]["amount"]["$ref"].as_str().unwrap();
+        let amount_id = amount_ref.strip_prefix("#/$defs/").unwrap();
+        assert_eq!(schema["$defs"][amount_id]["type"], "integer");
+
+        assert!(schema["calls"]["Balances"]["transfer_allow_death"].is_object());
+
+        // Every reference can be resolved:
+        let defs = schema["$defs"].as_object().unwrap();
+        let json = schema.to_string();
+        for id in json.split("\"#/$defs/").skip(1) {
+            let id = id.split('"').next().unwrap();
+            assert!(defs.contains_key(id), "type {id} is missing from the $defs");
+        }
+    }
+
+    #[tokio::test]
+    async fn unknown_pallets_are_an_error() {
+        assert!(run("--pallets NotAPallet").await.is_err());
+    }
+}
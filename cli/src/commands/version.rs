@@ -1,17 +1,32 @@
+use crate::utils::OutputFormat;
 use clap::Parser as ClapParser;
 
 /// Prints version information
 #[derive(Debug, ClapParser)]
-pub struct Opts {}
+pub struct Opts {
+    /// The format to print the version information in.
+    #[clap(long, value_enum, default_value_t = OutputFormat::Plain)]
+    output: OutputFormat,
+}
 
-pub fn run(_opts: Opts, output: &mut impl std::io::Write) -> color_eyre::Result<()> {
+pub fn run(opts: Opts, output: &mut impl std::io::Write) -> color_eyre::Result<()> {
     let git_hash = env!("GIT_HASH");
-    writeln!(
-        output,
-        "{} {}-{}",
-        clap::crate_name!(),
-        clap::crate_version!(),
-        git_hash
-    )?;
+    match opts.output {
+        OutputFormat::Plain => writeln!(
+            output,
+            "{} {}-{}",
+            clap::crate_name!(),
+            clap::crate_version!(),
+            git_hash
+        )?,
+        OutputFormat::Json => {
+            let json = serde_json::json!({
+                "name": clap::crate_name!(),
+                "version": clap::crate_version!(),
+                "gitHash": git_hash,
+            });
+            writeln!(output, "{}", serde_json::to_string_pretty(&json)?)?
+        }
+    }
     Ok(())
 }
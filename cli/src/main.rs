@@ -19,14 +19,21 @@ enum Command {
     Version(commands::version::Opts),
     Explore(commands::explore::Opts),
     ChainSpec(commands::chain_spec::Opts),
+    Schema(commands::schema::Opts),
 }
 
+/// The exit code used when a command fails because we couldn't talk to a node.
+const TRANSPORT_ERROR_EXIT_CODE: i32 = 2;
+/// The exit code used when a command fails because a node we talked to rejected, or
+/// failed to execute, what we asked of it. Any other failure exits with code 1.
+const DISPATCH_ERROR_EXIT_CODE: i32 = 3;
+
 #[tokio::main]
 async fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
     let args = Command::parse();
     let mut output = std::io::stdout();
-    match args {
+    let result = match args {
         Command::Metadata(opts) => commands::metadata::run(opts, &mut output).await,
         Command::Codegen(opts) => commands::codegen::run(opts, &mut output).await,
         Command::Compatibility(opts) => commands::compatibility::run(opts, &mut output).await,
@@ -34,5 +41,130 @@ async fn main() -> color_eyre::Result<()> {
         Command::Version(opts) => commands::version::run(opts, &mut output),
         Command::Explore(opts) => commands::explore::run(opts, &mut output).await,
         Command::ChainSpec(opts) => commands::chain_spec::run(opts, &mut output).await,
+        Command::Schema(opts) => commands::schema::run(opts, &mut output).await,
+    };
+
+    if let Err(err) = &result {
+        let exit_code = match error_class(err) {
+            ErrorClass::Transport => TRANSPORT_ERROR_EXIT_CODE,
+            ErrorClass::Dispatch => DISPATCH_ERROR_EXIT_CODE,
+            ErrorClass::Other => return result,
+        };
+        eprintln!("Error: {err:?}");
+        std::process::exit(exit_code);
+    }
+    result
+}
+
+/// The broad reason that a command failed, used to pick an exit code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ErrorClass {
+    /// We were unable to communicate with a node.
+    Transport,
+    /// A node rejected, or failed to execute, a request that we made.
+    Dispatch,
+    /// Anything else.
+    Other,
+}
+
+/// Work out why a command failed, based on the errors in the chain of the given report.
+fn error_class(err: &color_eyre::Report) -> ErrorClass {
+    use subxt::error::RpcError;
+    use subxt_codegen::error::FetchMetadataError;
+
+    fn jsonrpsee_error_class(e: &jsonrpsee::core::ClientError) -> ErrorClass {
+        use jsonrpsee::core::ClientError;
+        match e {
+            ClientError::Transport(_)
+            | ClientError::RestartNeeded(_)
+            | ClientError::RequestTimeout => ErrorClass::Transport,
+            ClientError::Call(_) => ErrorClass::Dispatch,
+            _ => ErrorClass::Other,
+        }
+    }
+
+    err.chain()
+        .map(|e| {
+            if let Some(e) = e.downcast_ref::<jsonrpsee::core::ClientError>() {
+                return jsonrpsee_error_class(e);
+            }
+            if let Some(FetchMetadataError::RequestError(e)) =
+                e.downcast_ref::<FetchMetadataError>()
+            {
+                return jsonrpsee_error_class(e);
+            }
+            match e.downcast_ref::<subxt::Error>() {
+                Some(subxt::Error::Rpc(e)) if e.is_transient() => ErrorClass::Transport,
+                Some(subxt::Error::Rpc(RpcError::RequestRejected(_)))
+                | Some(subxt::Error::Runtime(_)) => ErrorClass::Dispatch,
+                Some(subxt::Error::Rpc(RpcError::ClientError(e))) => e
+                    .downcast_ref::<jsonrpsee::core::ClientError>()
+                    .map_or(ErrorClass::Other, jsonrpsee_error_class),
+                _ => ErrorClass::Other,
+            }
+        })
+        .find(|class| *class != ErrorClass::Other)
+        .unwrap_or(ErrorClass::Other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{error_class, ErrorClass};
+    use color_eyre::eyre::{eyre, WrapErr};
+    use jsonrpsee::core::ClientError;
+    use subxt::error::RpcError;
+
+    fn call_error() -> ClientError {
+        ClientError::Call(jsonrpsee::types::ErrorObject::owned(
+            -32000,
+            "Client error: Execution failed",
+            None::<()>,
+        ))
+    }
+
+    #[test]
+    fn transport_errors_are_detected() {
+        let rpc_error: color_eyre::Report = subxt::Error::Rpc(RpcError::SubscriptionDropped).into();
+        assert_eq!(error_class(&rpc_error), ErrorClass::Transport);
+
+        let client_error: color_eyre::Report = ClientError::RequestTimeout.into();
+        assert_eq!(error_class(&client_error), ErrorClass::Transport);
+
+        // Transport errors are still found when context has been added to them:
+        let wrapped = Err::<(), _>(ClientError::RequestTimeout)
+            .wrap_err("Failed to fetch metadata")
+            .unwrap_err();
+        assert_eq!(error_class(&wrapped), ErrorClass::Transport);
+    }
+
+    #[test]
+    fn dispatch_errors_are_not_transport_errors() {
+        // The node answered, but with an error:
+        let client_error: color_eyre::Report = call_error().into();
+        assert_eq!(error_class(&client_error), ErrorClass::Dispatch);
+
+        let rpc_error: color_eyre::Report =
+            subxt::Error::Rpc(RpcError::ClientError(Box::new(call_error()))).into();
+        assert_eq!(error_class(&rpc_error), ErrorClass::Dispatch);
+
+        let rejected: color_eyre::Report =
+            subxt::Error::Rpc(RpcError::RequestRejected("invalid params".into())).into();
+        assert_eq!(error_class(&rejected), ErrorClass::Dispatch);
+    }
+
+    #[test]
+    fn other_errors_are_not_transport_errors() {
+        assert_eq!(
+            error_class(&eyre!("something else went wrong")),
+            ErrorClass::Other
+        );
+
+        let decode_error: color_eyre::Report =
+            subxt::Error::Other("could not decode".into()).into();
+        assert_eq!(error_class(&decode_error), ErrorClass::Other);
+
+        let parse_error: color_eyre::Report =
+            ClientError::Custom("unexpected response".into()).into();
+        assert_eq!(error_class(&parse_error), ErrorClass::Other);
     }
 }
@@ -16,6 +16,16 @@ use subxt::{OnlineClient, PolkadotConfig};
 use scale_value::Value;
 use subxt_codegen::fetch_metadata::{fetch_metadata_from_url, MetadataVersion, Url};
 
+/// The format that a command writes its output in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable text.
+    #[default]
+    Plain,
+    /// Machine readable JSON, intended for use in scripts.
+    Json,
+}
+
 /// The source of the metadata.
 #[derive(Debug, Args, Clone)]
 pub struct FileOrUrl {
@@ -0,0 +1,164 @@
+// Copyright 2019-2024 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::error::{BlockError, Error};
+use crate::Metadata;
+use alloc::string::String;
+use alloc::vec::Vec;
+use scale_value::{Composite, Value, ValueDef};
+
+/// A decoded call, along with any calls nested inside of it. Calls like `Sudo::sudo`,
+/// `Utility::batch`, `Proxy::proxy` and `Multisig::as_multi` each wrap one or more other
+/// calls, and those are decoded into [`CallTree::inner_calls`] so that the real operations
+/// being performed can be seen.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallTree {
+    /// The name of the pallet that the call belongs to.
+    pub pallet_name: String,
+    /// The name of the call.
+    pub call_name: String,
+    /// The decoded arguments given to the call. Any nested calls are included here too,
+    /// in their dynamic form.
+    pub fields: Composite<u32>,
+    /// The calls nested somewhere inside the arguments to this call, in the order that
+    /// they appear. Each of these may contain further nested calls in turn.
+    pub inner_calls: Vec<CallTree>,
+}
+
+impl CallTree {
+    /// Decode the given call bytes (the pallet index, call index and call arguments) into
+    /// a [`CallTree`], recursively decoding any calls nested inside it.
+    ///
+    /// Returns [`BlockError::UnexpectedCallShape`] if the call type in the metadata isn't a
+    /// variant for each pallet wrapping a variant for each call.
+    pub fn decode(call_bytes: &[u8], metadata: &Metadata) -> Result<CallTree, Error> {
+        let call_ty = metadata.outer_enums().call_enum_ty();
+        let value =
+            scale_value::scale::decode_as_type(&mut &*call_bytes, call_ty, metadata.types())?;
+        CallTree::from_value(value, call_ty).ok_or(Error::Block(BlockError::UnexpectedCallShape))
+    }
+
+    /// Build a tree from a call decoded into the outer `RuntimeCall` enum, which is a
+    /// variant for the pallet wrapping a variant for the call itself.
+    fn from_value(value: Value<u32>, call_ty: u32) -> Option<CallTree> {
+        let ValueDef::Variant(pallet) = value.value else {
+            return None;
+        };
+        let mut pallet_values = pallet.values.into_values();
+        let (Some(call), None) = (pallet_values.next(), pallet_values.next()) else {
+            return None;
+        };
+        let ValueDef::Variant(call) = call.value else {
+            return None;
+        };
+
+        let mut inner_calls = Vec::new();
+        for field in call.values.values() {
+            find_calls(field, call_ty, &mut inner_calls)?;
+        }
+
+        Some(CallTree {
+            pallet_name: pallet.name,
+            call_name: call.name,
+            fields: call.values,
+            inner_calls,
+        })
+    }
+}
+
+/// Find every value which has the outer call type, without looking inside them (their own
+/// nested calls are found when they are turned into a [`CallTree`]).
+fn find_calls(value: &Value<u32>, call_ty: u32, calls: &mut Vec<CallTree>) -> Option<()> {
+    if value.context == call_ty {
+        calls.push(CallTree::from_value(value.clone(), call_ty)?);
+        return Some(());
+    }
+    match &value.value {
+        ValueDef::Composite(composite) => {
+            for value in composite.values() {
+                find_calls(value, call_ty, calls)?;
+            }
+        }
+        ValueDef::Variant(variant) => {
+            for value in variant.values.values() {
+                find_calls(value, call_ty, calls)?;
+            }
+        }
+        _ => {}
+    }
+    Some(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use scale_value::Variant;
+
+    const CALL_TY: u32 = 100;
+
+    fn with_ty(value: ValueDef<u32>, ty: u32) -> Value<u32> {
+        Value { value, context: ty }
+    }
+
+    fn runtime_call(pallet: &str, call: &str, fields: Vec<(&str, Value<u32>)>) -> Value<u32> {
+        let fields = fields.into_iter().map(|(n, v)| (n.into(), v)).collect();
+        let call = with_ty(
+            ValueDef::Variant(Variant {
+                name: call.into(),
+                values: Composite::Named(fields),
+            }),
+            1,
+        );
+        with_ty(
+            ValueDef::Variant(Variant {
+                name: pallet.into(),
+                values: Composite::Unnamed(vec![call]),
+            }),
+            CALL_TY,
+        )
+    }
+
+    fn remark(bytes: Vec<u8>) -> Value<u32> {
+        let bytes = Value::from_bytes(bytes).map_context(|_| 2);
+        runtime_call("System", "remark", vec![("remark", bytes)])
+    }
+
+    #[test]
+    fn nested_calls_are_decoded() {
+        let batch = runtime_call(
+            "Utility",
+            "batch",
+            vec![(
+                "calls",
+                with_ty(
+                    ValueDef::Composite(Composite::Unnamed(vec![remark(vec![1]), remark(vec![2])])),
+                    3,
+                ),
+            )],
+        );
+        let sudo = runtime_call("Sudo", "sudo", vec![("call", batch)]);
+
+        let tree = CallTree::from_value(sudo, CALL_TY).unwrap();
+        assert_eq!(tree.pallet_name, "Sudo");
+        assert_eq!(tree.call_name, "sudo");
+        assert_eq!(tree.inner_calls.len(), 1);
+
+        let batch = &tree.inner_calls[0];
+        assert_eq!(batch.call_name, "batch");
+        assert_eq!(batch.inner_calls.len(), 2);
+        assert!(batch
+            .inner_calls
+            .iter()
+            .all(|c| c.call_name == "remark" && c.inner_calls.is_empty()));
+    }
+
+    #[test]
+    fn calls_without_nesting_have_no_inner_calls() {
+        let tree = CallTree::from_value(remark(vec![1, 2, 3]), CALL_TY).unwrap();
+        assert_eq!(tree.pallet_name, "System");
+        assert_eq!(tree.fields.len(), 1);
+        assert!(tree.inner_calls.is_empty());
+    }
+}
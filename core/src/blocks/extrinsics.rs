@@ -3,6 +3,7 @@
 // see LICENSE for license details.
 
 use crate::blocks::extrinsic_signed_extensions::ExtrinsicSignedExtensions;
+use crate::blocks::CallTree;
 use crate::utils::strip_compact_prefix;
 use crate::{
     config::Config,
@@ -13,6 +14,7 @@ use alloc::sync::Arc;
 use alloc::vec::Vec;
 use codec::Decode;
 use scale_decode::DecodeAsType;
+use sp_crypto_hashing::blake2_256;
 use subxt_metadata::PalletMetadata;
 
 pub use crate::blocks::StaticExtrinsic;
@@ -120,6 +122,56 @@ impl<T: Config> Extrinsics<T> {
     pub fn has<E: StaticExtrinsic>(&self) -> Result<bool, Error> {
         Ok(self.find::<E>().next().transpose()?.is_some())
     }
+
+    /// Verify the signatures of every signed extrinsic in the block in one go, by handing them
+    /// to a batch verification function such as `subxt_signer::sr25519::verify_signed_payloads()`.
+    /// Unsigned extrinsics are skipped.
+    ///
+    /// The payload that was signed includes some "additional" signed extension bytes which are not
+    /// part of the extrinsic itself (see [`ExtrinsicDetails::signer_payload()`]), and so
+    /// `additional_signed` is called to obtain these for each signed extrinsic.
+    ///
+    /// Returns `true` if every signature is valid, as determined by `verify_batch`.
+    pub fn verify_signatures_batch<A, V>(
+        &self,
+        mut additional_signed: A,
+        verify_batch: V,
+    ) -> Result<bool, Error>
+    where
+        A: FnMut(&ExtrinsicDetails<T>) -> Result<Vec<u8>, Error>,
+        V: FnOnce(&[SignedPayload<'_>]) -> bool,
+    {
+        let extrinsics = self.iter().collect::<Result<Vec<_>, _>>()?;
+        let mut payloads = Vec::new();
+        for ext in &extrinsics {
+            let (Some(address), Some(signature)) = (ext.address_bytes(), ext.signature_bytes())
+            else {
+                continue;
+            };
+            let additional = additional_signed(ext)?;
+            let payload = ext
+                .signer_payload(&additional)
+                .expect("extrinsic is signed; qed");
+            payloads.push(SignedPayload {
+                address,
+                signature,
+                payload,
+            });
+        }
+        Ok(verify_batch(&payloads))
+    }
+}
+
+/// The parts of a signed extrinsic needed to verify its signature. See
+/// [`Extrinsics::verify_signatures_batch()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedPayload<'a> {
+    /// The SCALE encoded address of the signer.
+    pub address: &'a [u8],
+    /// The SCALE encoded signature.
+    pub signature: &'a [u8],
+    /// The payload that the signature should be valid for.
+    pub payload: Vec<u8>,
 }
 
 /// A single extrinsic in a block.
@@ -323,6 +375,24 @@ where
             .map(|e| &self.bytes[e.signature_end_idx..e.extra_end_idx])
     }
 
+    /// Return the payload that was signed to produce this extrinsic's signature. This is the call
+    /// data, followed by the signed extension `extra` bytes (see [`Self::signed_extensions_bytes()`]),
+    /// followed by the `additional` signed extension bytes given. These last bytes are signed but
+    /// are not part of the extrinsic (they include things like the genesis hash and runtime version),
+    /// and so must be provided. As when signing, the payload is hashed if it's over 256 bytes long.
+    ///
+    /// Note: Returns `None` if the extrinsic is not signed.
+    pub fn signer_payload(&self, additional_signed: &[u8]) -> Option<Vec<u8>> {
+        let mut bytes = self.call_bytes().to_vec();
+        bytes.extend_from_slice(self.signed_extensions_bytes()?);
+        bytes.extend_from_slice(additional_signed);
+        if bytes.len() > 256 {
+            Some(blake2_256(&bytes).to_vec())
+        } else {
+            Some(bytes)
+        }
+    }
+
     /// Returns `None` if the extrinsic is not signed.
     pub fn signed_extensions(&self) -> Option<ExtrinsicSignedExtensions<'_, T>> {
         let signed = self.signed_details.as_ref()?;
@@ -377,6 +447,12 @@ where
         Ok(decoded)
     }
 
+    /// Decode the call in this extrinsic into a [`CallTree`], which also contains any calls
+    /// nested inside it (for instance, the calls in a `Utility::batch`).
+    pub fn call_tree(&self) -> Result<CallTree, Error> {
+        CallTree::decode(self.call_bytes(), &self.metadata)
+    }
+
     /// Attempt to decode these [`ExtrinsicDetails`] into a type representing the extrinsic fields.
     /// Such types are exposed in the codegen as `pallet_name::calls::types::CallName` types.
     pub fn as_extrinsic<E: StaticExtrinsic>(&self) -> Result<Option<E>, Error> {
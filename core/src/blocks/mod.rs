@@ -66,6 +66,7 @@
 //! # ]);
 //! ```
 
+mod call_tree;
 mod extrinsic_signed_extensions;
 mod extrinsics;
 mod static_extrinsic;
@@ -75,9 +76,11 @@ use crate::error::BlockError;
 use crate::Metadata;
 use alloc::vec::Vec;
 
+pub use call_tree::CallTree;
 pub use extrinsic_signed_extensions::{ExtrinsicSignedExtension, ExtrinsicSignedExtensions};
 pub use extrinsics::{
     ExtrinsicDetails, ExtrinsicMetadataDetails, Extrinsics, FoundExtrinsic, SignedExtrinsicDetails,
+    SignedPayload,
 };
 pub use static_extrinsic::StaticExtrinsic;
 
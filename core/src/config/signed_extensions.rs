@@ -157,7 +157,9 @@ pub struct CheckMortality<T: Config> {
 }
 
 /// Parameters to configure the [`CheckMortality`] signed extension.
+#[derive_where(Clone)]
 pub struct CheckMortalityParams<T: Config>(Option<CheckMortalityParamsInner<T>>);
+#[derive_where(Clone)]
 struct CheckMortalityParamsInner<T: Config> {
     era: Era,
     checkpoint: Option<T::Hash>,
@@ -257,6 +259,7 @@ impl<T: Config> ChargeAssetTxPayment<T> {
 }
 
 /// Parameters to configure the [`ChargeAssetTxPayment`] signed extension.
+#[derive_where(Clone)]
 pub struct ChargeAssetTxPaymentParams<T: Config> {
     tip: u128,
     asset_id: Option<T::AssetId>,
@@ -335,7 +338,7 @@ impl ChargeTransactionPayment {
 }
 
 /// Parameters to configure the [`ChargeTransactionPayment`] signed extension.
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct ChargeTransactionPaymentParams {
     tip: u128,
 }
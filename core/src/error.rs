@@ -59,6 +59,9 @@ pub enum BlockError {
     /// Decoding error.
     #[display(fmt = "Cannot decode extrinsic: {_0}")]
     DecodingError(codec::Error),
+    /// The call could not be decoded into a pallet variant wrapping a call variant.
+    #[display(fmt = "Cannot decode call: expected a pallet variant wrapping a call variant")]
+    UnexpectedCallShape,
 }
 
 #[cfg(feature = "std")]
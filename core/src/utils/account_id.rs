@@ -74,6 +74,11 @@ impl AccountId32 {
     // implement the logic needed to decode an AccountId32 from an SS58 encoded string. This is exposed
     // via a `FromStr` impl.
     fn from_ss58check(s: &str) -> Result<Self, FromSs58Error> {
+        Self::from_ss58check_with_prefix(s).map(|(account_id, _)| account_id)
+    }
+
+    // Decode an SS58 encoded string, returning the account ID and the network prefix it was encoded with.
+    fn from_ss58check_with_prefix(s: &str) -> Result<(Self, u16), FromSs58Error> {
         const CHECKSUM_LEN: usize = 2;
         let body_len = 32;
 
@@ -82,9 +87,14 @@ impl AccountId32 {
         if data.len() < 2 {
             return Err(FromSs58Error::BadLength);
         }
-        let prefix_len = match data[0] {
-            0..=63 => 1,
-            64..=127 => 2,
+        let (prefix_len, prefix) = match data[0] {
+            0..=63 => (1, data[0] as u16),
+            64..=127 => {
+                // Two byte prefixes are encoded as per `sp_core::crypto::Ss58Codec`.
+                let lower = (data[0] << 2) | (data[1] >> 6);
+                let upper = data[1] & 0b00111111;
+                (2, (lower as u16) | ((upper as u16) << 8))
+            }
             _ => return Err(FromSs58Error::InvalidPrefix),
         };
         if data.len() != prefix_len + body_len + CHECKSUM_LEN {
@@ -100,10 +110,62 @@ impl AccountId32 {
         let result = data[prefix_len..body_len + prefix_len]
             .try_into()
             .map_err(|_| FromSs58Error::BadLength)?;
-        Ok(AccountId32(result))
+        Ok((AccountId32(result), prefix))
+    }
+
+    /// Parse a string into an [`AccountId32`], being as strict or as lenient as the
+    /// given [`Ss58ParseMode`] dictates. Along with the account ID, this returns the
+    /// [`AddressFormat`] that the string was in, so that callers accepting any prefix
+    /// can warn if the prefix is not the one they expected.
+    ///
+    /// The [`core::str::FromStr`] impl is equivalent to using [`Ss58ParseMode::AnyPrefix`].
+    pub fn parse(s: &str, mode: Ss58ParseMode) -> Result<(Self, AddressFormat), FromSs58Error> {
+        if let (Ss58ParseMode::AllowHex, Some(hex_str)) = (mode, s.strip_prefix("0x")) {
+            let bytes = hex::decode(hex_str).map_err(|_| FromSs58Error::BadHex)?;
+            let bytes: [u8; 32] = bytes.try_into().map_err(|_| FromSs58Error::BadLength)?;
+            return Ok((AccountId32(bytes), AddressFormat::Hex));
+        }
+
+        let (account_id, prefix) = Self::from_ss58check_with_prefix(s)?;
+        if let Ss58ParseMode::Strict { prefix: expected } = mode {
+            if prefix != expected {
+                return Err(FromSs58Error::UnexpectedPrefix {
+                    expected,
+                    found: prefix,
+                });
+            }
+        }
+        Ok((account_id, AddressFormat::Ss58 { prefix }))
     }
 }
 
+/// How strictly to interpret a string as an [`AccountId32`]. See [`AccountId32::parse()`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Ss58ParseMode {
+    /// Only accept SS58 encoded addresses with exactly the given network prefix.
+    Strict {
+        /// The network prefix that the address must have been encoded with.
+        prefix: u16,
+    },
+    /// Accept SS58 encoded addresses with any network prefix.
+    AnyPrefix,
+    /// Accept SS58 encoded addresses with any network prefix, and fall back to
+    /// accepting a `0x` prefixed, hex encoded 32 byte account ID.
+    AllowHex,
+}
+
+/// The format that some string was in when it was parsed into an [`AccountId32`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum AddressFormat {
+    /// An SS58 encoded address with the given network prefix.
+    Ss58 {
+        /// The network prefix that the address was encoded with.
+        prefix: u16,
+    },
+    /// A `0x` prefixed, hex encoded account ID.
+    Hex,
+}
+
 /// An error obtained from trying to interpret an SS58 encoded string into an AccountId32
 #[derive(Clone, Copy, Eq, PartialEq, Debug, Display)]
 #[allow(missing_docs)]
@@ -116,6 +178,10 @@ pub enum FromSs58Error {
     InvalidChecksum,
     #[display(fmt = "Invalid SS58 prefix byte.")]
     InvalidPrefix,
+    #[display(fmt = "Unexpected SS58 prefix {found} (expected {expected})")]
+    UnexpectedPrefix { expected: u16, found: u16 },
+    #[display(fmt = "Invalid hex")]
+    BadHex,
 }
 
 #[cfg(feature = "std")]
@@ -223,4 +289,49 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn parse_reports_and_checks_prefix() {
+        use sp_core::crypto::Ss58AddressFormat;
+
+        let substrate_account = AccountKeyring::Alice.to_account_id();
+        let local_account = AccountId32(substrate_account.clone().into());
+
+        // Both single and two byte prefixes are reported correctly:
+        for prefix in [0, 2, 42, 1284] {
+            let ss58 =
+                substrate_account.to_ss58check_with_version(Ss58AddressFormat::custom(prefix));
+            let parsed = AccountId32::parse(&ss58, Ss58ParseMode::AnyPrefix).unwrap();
+            assert_eq!(
+                parsed,
+                (local_account.clone(), AddressFormat::Ss58 { prefix })
+            );
+        }
+
+        let polkadot_ss58 =
+            substrate_account.to_ss58check_with_version(Ss58AddressFormat::custom(0));
+        assert!(AccountId32::parse(&polkadot_ss58, Ss58ParseMode::Strict { prefix: 0 }).is_ok());
+        assert_eq!(
+            AccountId32::parse(&polkadot_ss58, Ss58ParseMode::Strict { prefix: 2 }),
+            Err(FromSs58Error::UnexpectedPrefix {
+                expected: 2,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn parse_only_accepts_hex_when_allowed() {
+        let hex_account = format!("0x{}", hex::encode([7u8; 32]));
+
+        assert_eq!(
+            AccountId32::parse(&hex_account, Ss58ParseMode::AllowHex),
+            Ok((AccountId32([7; 32]), AddressFormat::Hex))
+        );
+        assert!(AccountId32::parse(&hex_account, Ss58ParseMode::AnyPrefix).is_err());
+        assert_eq!(
+            AccountId32::parse("0x0102", Ss58ParseMode::AllowHex),
+            Err(FromSs58Error::BadLength)
+        );
+    }
 }
@@ -20,7 +20,7 @@ use alloc::vec::Vec;
 use codec::{Compact, Decode, Encode};
 use derive_where::derive_where;
 
-pub use account_id::AccountId32;
+pub use account_id::{AccountId32, AddressFormat, FromSs58Error, Ss58ParseMode};
 pub use era::Era;
 pub use multi_address::MultiAddress;
 pub use multi_signature::MultiSignature;
@@ -0,0 +1,119 @@
+#![allow(missing_docs)]
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use subxt::backend::{legacy::LegacyRpcMethods, rpc::RpcClient};
+use subxt::blocks::Block;
+use subxt::config::Header;
+use subxt::events::Events;
+use subxt::{OnlineClient, PolkadotConfig};
+
+#[subxt::subxt(runtime_metadata_path = "../artifacts/polkadot_metadata_small.scale")]
+pub mod polkadot {}
+
+type Client = OnlineClient<PolkadotConfig>;
+
+/// A minimal indexer skeleton. It works through every finalized block from the last
+/// checkpoint onwards, hands each block and its events to a callback, and records its
+/// progress after every block so that it can pick up where it left off if restarted.
+struct Crawler {
+    api: Client,
+    rpc: LegacyRpcMethods<PolkadotConfig>,
+    checkpoint_path: PathBuf,
+    /// Never fetch blocks more quickly than this, to avoid overloading the node.
+    min_interval: Duration,
+    /// How long to wait before checking for new blocks once we've caught up.
+    poll_interval: Duration,
+}
+
+impl Crawler {
+    /// Crawl blocks forever, starting from the block after the last checkpoint (or from
+    /// `start` if there is no checkpoint yet), calling `on_block` with each one in order.
+    async fn run<F>(&self, start: u64, mut on_block: F) -> Result<(), Box<dyn std::error::Error>>
+    where
+        F: FnMut(&Block<PolkadotConfig, Client>, &Events<PolkadotConfig>),
+    {
+        let mut next = match load_checkpoint(&self.checkpoint_path)? {
+            Some(last) => last + 1,
+            None => start,
+        };
+
+        let mut rate_limit = tokio::time::interval(self.min_interval);
+        rate_limit.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            let finalized_hash = self.rpc.chain_get_finalized_head().await?;
+            let finalized_number: u64 = self
+                .rpc
+                .chain_get_header(Some(finalized_hash))
+                .await?
+                .ok_or("finalized block not found")?
+                .number()
+                .into();
+
+            while next <= finalized_number {
+                rate_limit.tick().await;
+
+                let hash = self
+                    .rpc
+                    .chain_get_block_hash(Some(next.into()))
+                    .await?
+                    .ok_or_else(|| format!("block {next} not found"))?;
+                let block = self.api.blocks().at(hash).await?;
+                let events = block.events().await?;
+
+                on_block(&block, &events);
+
+                // Only once the block has been handled do we record it, so that a
+                // crash part way through means it is handled again on restart.
+                save_checkpoint(&self.checkpoint_path, next)?;
+                next += 1;
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
+
+/// Load the number of the last block that was fully handled, if there is one.
+fn load_checkpoint(path: &Path) -> Result<Option<u64>, Box<dyn std::error::Error>> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents.trim().parse()?)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Record the number of the last block that was fully handled. We write to a temporary
+/// file and rename it into place, so that the checkpoint is never left half written.
+fn save_checkpoint(path: &Path, block_number: u64) -> std::io::Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, block_number.to_string())?;
+    std::fs::rename(tmp_path, path)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rpc_client = RpcClient::from_url("ws://127.0.0.1:9944").await?;
+    let rpc = LegacyRpcMethods::<PolkadotConfig>::new(rpc_client.clone());
+    let api = OnlineClient::<PolkadotConfig>::from_rpc_client(rpc_client).await?;
+
+    let crawler = Crawler {
+        api,
+        rpc,
+        checkpoint_path: PathBuf::from("crawler.checkpoint"),
+        min_interval: Duration::from_millis(100),
+        poll_interval: Duration::from_secs(6),
+    };
+
+    // Index every balance transfer, starting from genesis on the first run:
+    crawler
+        .run(0, |block, events| {
+            for transfer in events.find::<polkadot::balances::events::Transfer>() {
+                match transfer {
+                    Ok(t) => println!("#{}: {} -> {}: {}", block.number(), t.from, t.to, t.amount),
+                    Err(e) => eprintln!("#{}: failed to decode transfer: {e}", block.number()),
+                }
+            }
+        })
+        .await
+}
@@ -0,0 +1,76 @@
+#![allow(missing_docs)]
+use subxt::{OnlineClient, PolkadotConfig};
+
+#[subxt::subxt(runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale")]
+pub mod polkadot {}
+
+use polkadot::balances::events::Transfer;
+use polkadot::runtime_types::frame_support::dispatch::{DispatchClass, DispatchInfo};
+use polkadot::system::events::{ExtrinsicFailed, ExtrinsicSuccess};
+use polkadot::transaction_payment::events::TransactionFeePaid;
+
+/// Some totals computed from the extrinsics and events in a single block.
+#[derive(Debug, Default)]
+struct BlockSummary {
+    extrinsics_succeeded: u32,
+    extrinsics_failed: u32,
+    fees_paid: u128,
+    tips_paid: u128,
+    transfer_count: u32,
+    transfer_volume: u128,
+    // The `ref_time` weight used by extrinsics in each dispatch class.
+    normal_weight: u64,
+    operational_weight: u64,
+    mandatory_weight: u64,
+}
+
+impl BlockSummary {
+    fn add_weight(&mut self, info: &DispatchInfo) {
+        let weight = match info.class {
+            DispatchClass::Normal => &mut self.normal_weight,
+            DispatchClass::Operational => &mut self.operational_weight,
+            DispatchClass::Mandatory => &mut self.mandatory_weight,
+        };
+        *weight += info.weight.ref_time;
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Create a client to use:
+    let api = OnlineClient::<PolkadotConfig>::new().await?;
+
+    // Subscribe to all finalized blocks:
+    let mut blocks_sub = api.blocks().subscribe_finalized().await?;
+
+    // For each block, compute and print a summary of it. A single pass over the
+    // events is enough; every extrinsic emits exactly one of `ExtrinsicSuccess`
+    // or `ExtrinsicFailed`, and fees are reported via `TransactionFeePaid`.
+    while let Some(block) = blocks_sub.next().await {
+        let block = block?;
+        let mut summary = BlockSummary::default();
+
+        for event in block.events().await?.iter() {
+            let event = event?;
+
+            if let Some(ev) = event.as_event::<ExtrinsicSuccess>()? {
+                summary.extrinsics_succeeded += 1;
+                summary.add_weight(&ev.dispatch_info);
+            } else if let Some(ev) = event.as_event::<ExtrinsicFailed>()? {
+                summary.extrinsics_failed += 1;
+                summary.add_weight(&ev.dispatch_info);
+            } else if let Some(ev) = event.as_event::<TransactionFeePaid>()? {
+                summary.fees_paid += ev.actual_fee;
+                summary.tips_paid += ev.tip;
+            } else if let Some(ev) = event.as_event::<Transfer>()? {
+                summary.transfer_count += 1;
+                summary.transfer_volume += ev.amount;
+            }
+        }
+
+        println!("Block #{} ({}):", block.number(), block.hash());
+        println!("  {summary:?}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+#![allow(missing_docs)]
+use subxt::backend::{legacy::LegacyRpcMethods, rpc::RpcClient};
+use subxt::config::Header;
+use subxt::ext::codec::{Compact, Decode};
+use subxt::{OnlineClient, PolkadotConfig};
+
+/// A `Weight`, as stored in the runtime.
+#[derive(Decode)]
+struct Weight {
+    ref_time: Compact<u64>,
+    _proof_size: Compact<u64>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rpc_client = RpcClient::from_url("ws://127.0.0.1:9944").await?;
+    let rpc = LegacyRpcMethods::<PolkadotConfig>::new(rpc_client.clone());
+    let api = OnlineClient::<PolkadotConfig>::from_rpc_client(rpc_client).await?;
+
+    // The max weight of a block is the second field in the `System::BlockWeights` constant.
+    let block_weights = api
+        .metadata()
+        .pallet_by_name("System")
+        .ok_or("System pallet not found")?
+        .constant_by_name("BlockWeights")
+        .ok_or("System::BlockWeights constant not found")?
+        .value()
+        .to_vec();
+    let (_base_block, max_block) = <(Weight, Weight)>::decode(&mut &*block_weights)?;
+
+    // These are the storage entries that we want to sample over a range of blocks.
+    let multiplier_key = api.storage().address_bytes(&subxt::dynamic::storage(
+        "TransactionPayment",
+        "NextFeeMultiplier",
+        (),
+    ))?;
+    let block_weight_key =
+        api.storage()
+            .address_bytes(&subxt::dynamic::storage("System", "BlockWeight", ()))?;
+
+    // Look at the last 100 finalized blocks.
+    let to = rpc.chain_get_finalized_head().await?;
+    let to_number = rpc
+        .chain_get_header(Some(to))
+        .await?
+        .ok_or("finalized block not found")?
+        .number();
+    let from = rpc
+        .chain_get_block_hash(Some(to_number.saturating_sub(100).into()))
+        .await?
+        .ok_or("block not found")?;
+
+    // `state_queryStorage` hands back the values at `from` and then only the changes in
+    // each subsequent block, so one request gives us the full history of both entries.
+    let change_sets = rpc
+        .state_query_storage([&*multiplier_key, &*block_weight_key], from, Some(to))
+        .await?;
+
+    let mut multiplier = 0u128;
+    let mut fullness = 0f64;
+    for change_set in change_sets {
+        for (key, value) in change_set.changes {
+            let Some(value) = value else { continue };
+            if key.0 == multiplier_key {
+                // A `FixedU128`, which has 18 decimal places.
+                multiplier = u128::decode(&mut &*value.0)?;
+            } else if key.0 == block_weight_key {
+                // The weight used by each dispatch class (normal, operational and mandatory).
+                let per_class = <[Weight; 3]>::decode(&mut &*value.0)?;
+                let used: u64 = per_class.iter().map(|w| w.ref_time.0).sum();
+                fullness = used as f64 / max_block.ref_time.0 as f64;
+            }
+        }
+
+        println!(
+            "Block {:?}: fee multiplier {:.6}, block fullness {:.2}%",
+            change_set.block,
+            multiplier as f64 / 1e18,
+            fullness * 100.0
+        );
+    }
+
+    Ok(())
+}
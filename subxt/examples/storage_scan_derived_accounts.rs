@@ -0,0 +1,75 @@
+#![allow(missing_docs)]
+use subxt::backend::{legacy::LegacyRpcMethods, rpc::RpcClient};
+use subxt::ext::codec::Decode;
+use subxt::utils::AccountId32;
+use subxt::{OnlineClient, PolkadotConfig};
+use subxt_signer::{bip39::Mnemonic, sr25519::Keypair, DeriveJunction, DEV_PHRASE};
+
+#[subxt::subxt(runtime_metadata_path = "../artifacts/polkadot_metadata_small.scale")]
+pub mod polkadot {}
+
+type AccountInfo = polkadot::runtime_types::frame_system::AccountInfo<
+    u32,
+    polkadot::runtime_types::pallet_balances::types::AccountData<u128>,
+>;
+
+/// How many accounts to derive and scan.
+const NUM_ACCOUNTS: u64 = 50;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rpc_client = RpcClient::from_url("ws://127.0.0.1:9944").await?;
+    let rpc = LegacyRpcMethods::<PolkadotConfig>::new(rpc_client.clone());
+    let api = OnlineClient::<PolkadotConfig>::from_rpc_client(rpc_client).await?;
+
+    // The phrase to derive accounts from can be given as the first argument:
+    let phrase = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEV_PHRASE.to_string());
+    let root = Keypair::from_phrase(&Mnemonic::parse(phrase)?, None)?;
+
+    // Derive "//0", "//1" and so on. Numeric junctions are encoded as a u64,
+    // which matches how other tools interpret the same derivation paths.
+    let accounts: Vec<AccountId32> = (0..NUM_ACCOUNTS)
+        .map(|i| {
+            root.derive([DeriveJunction::hard(i)])
+                .public_key()
+                .to_account_id()
+        })
+        .collect();
+
+    // Build the System::Account storage key for each of them:
+    let keys = accounts
+        .iter()
+        .map(|account| {
+            let addr = polkadot::storage().system().account(account.clone());
+            api.storage().address_bytes(&addr)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Fetch every value in a single request, rather than one request per account:
+    let at = rpc.chain_get_finalized_head().await?;
+    let change_sets = rpc
+        .state_query_storage_at(keys.iter().map(|k| &**k), Some(at))
+        .await?;
+
+    let mut values = std::collections::HashMap::new();
+    for (key, value) in change_sets.into_iter().flat_map(|set| set.changes) {
+        if let Some(value) = value {
+            values.insert(key.0, AccountInfo::decode(&mut &*value.0)?);
+        }
+    }
+
+    // Report on any accounts which have been used:
+    for (i, (account, key)) in accounts.iter().zip(&keys).enumerate() {
+        let Some(info) = values.get(key) else {
+            continue;
+        };
+        println!(
+            "//{i} {account}: nonce {}, free {}, reserved {}",
+            info.nonce, info.data.free, info.data.reserved
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+#![allow(missing_docs)]
+use subxt::{utils::AccountId32, OnlineClient, PolkadotConfig};
+
+#[subxt::subxt(runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale")]
+pub mod polkadot {}
+
+use polkadot::runtime_types::pallet_vesting::vesting_info::VestingInfo;
+
+/// The amount still locked by a vesting schedule at the given block. Vesting
+/// is linear; nothing unlocks before the starting block, and then `per_block`
+/// unlocks each block until nothing is left.
+fn locked_at(schedule: &VestingInfo<u128, u32>, block_number: u32) -> u128 {
+    let vested_blocks = block_number.saturating_sub(schedule.starting_block);
+    let vested = schedule.per_block.saturating_mul(vested_blocks as u128);
+    schedule.locked.saturating_sub(vested)
+}
+
+/// The first block at which a vesting schedule has unlocked everything.
+fn fully_unlocked_at(schedule: &VestingInfo<u128, u32>) -> u32 {
+    if schedule.per_block == 0 {
+        return u32::MAX;
+    }
+    let blocks = schedule.locked.div_ceil(schedule.per_block);
+    schedule
+        .starting_block
+        .saturating_add(blocks.try_into().unwrap_or(u32::MAX))
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Create a new API client, configured to talk to Polkadot nodes.
+    let api = OnlineClient::<PolkadotConfig>::new().await?;
+
+    // The account whose vesting schedules we'd like to project, passed as an SS58 address:
+    let account: AccountId32 = std::env::args()
+        .nth(1)
+        .ok_or("expected an account address as the first argument")?
+        .parse()?;
+
+    // Fetch the account's vesting schedules at the latest block.
+    let block = api.blocks().at_latest().await?;
+    let current_block = block.number();
+    let vesting_addr = polkadot::storage().vesting().vesting(account.clone());
+    let Some(schedules) = block.storage().fetch(&vesting_addr).await? else {
+        println!("No vesting schedules found");
+        return Ok(());
+    };
+
+    println!("Vesting schedules at block #{current_block}:");
+    for (idx, schedule) in schedules.0.iter().enumerate() {
+        let locked_now = locked_at(schedule, current_block);
+        let unlocked_now = schedule.locked - locked_now;
+        let done_at = fully_unlocked_at(schedule);
+
+        println!("  Schedule {idx}:");
+        println!("    Unlocked so far: {unlocked_now} of {}", schedule.locked);
+        println!("    Still locked: {locked_now}");
+        if locked_now == 0 {
+            println!("    Fully unlocked");
+        } else {
+            let blocks_left = done_at.saturating_sub(current_block);
+            println!("    Fully unlocked at block #{done_at} ({blocks_left} blocks from now)");
+        }
+    }
+
+    // Vested amounts only become transferable once `Vesting::vest` is called, which
+    // shrinks the account's "vesting " balance lock to what is still locked. The
+    // difference between the current lock and what should still be locked is thus
+    // what calling `vest` would free up right now.
+    let total_locked_now: u128 = schedules
+        .0
+        .iter()
+        .map(|schedule| locked_at(schedule, current_block))
+        .sum();
+    let locks_addr = polkadot::storage().balances().locks(account);
+    let vesting_lock = block
+        .storage()
+        .fetch_or_default(&locks_addr)
+        .await?
+        .0
+        .into_iter()
+        .find(|lock| &lock.id == b"vesting ")
+        .map(|lock| lock.amount)
+        .unwrap_or(0);
+    let unlockable_now = vesting_lock.saturating_sub(total_locked_now);
+
+    println!("Total still locked by vesting: {total_locked_now}");
+    println!("Unlockable now by calling vest: {unlockable_now}");
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+#![allow(missing_docs)]
+use subxt::backend::{legacy::LegacyRpcMethods, rpc::RpcClient};
+use subxt::config::PolkadotConfig;
+use subxt::ext::codec::Encode;
+use subxt::ext::subxt_core;
+use subxt::utils::{AccountId32, MultiAddress};
+use subxt::OnlineClient;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rpc_client = RpcClient::from_url("ws://127.0.0.1:9944").await?;
+    let rpc = LegacyRpcMethods::<PolkadotConfig>::new(rpc_client.clone());
+    let api = OnlineClient::<PolkadotConfig>::from_rpc_client(rpc_client).await?;
+
+    // The account whose pending transactions we'd like to see, passed as an SS58 address:
+    let account: AccountId32 = std::env::args()
+        .nth(1)
+        .ok_or("expected an account address as the first argument")?
+        .parse()?;
+
+    // Signed extrinsics contain the encoded address of their signer, which we'll match on:
+    let address: MultiAddress<AccountId32, ()> = account.clone().into();
+    let address_bytes = address.encode();
+
+    // Fetch and decode everything in the node's transaction pool:
+    let pending = rpc.author_pending_extrinsics().await?;
+    let extrinsics = subxt_core::blocks::decode_from::<PolkadotConfig>(pending, api.metadata())?;
+
+    let mut from_account = Vec::new();
+    for ext in extrinsics.iter() {
+        let ext = ext?;
+        if ext.address_bytes() != Some(&address_bytes[..]) {
+            continue;
+        }
+        let nonce = ext.signed_extensions().and_then(|e| e.nonce());
+        let call = format!("{}::{}", ext.pallet_name()?, ext.variant_name()?);
+        from_account.push((nonce, call));
+    }
+    from_account.sort();
+
+    println!(
+        "{account} has {} pending transaction(s) in the pool:",
+        from_account.len()
+    );
+    for (nonce, call) in from_account {
+        let nonce = nonce.map_or("?".to_string(), |n| n.to_string());
+        println!("  nonce {nonce}: {call}");
+    }
+
+    // Any nonce from here on should not clash with a transaction already in the pool:
+    let next_nonce = rpc.system_account_next_index(&account).await?;
+    println!("Next available nonce: {next_nonce}");
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+#![allow(missing_docs)]
+use subxt::config::{substrate::BlakeTwo256, Hasher};
+use subxt::ext::codec::Encode;
+use subxt::{OnlineClient, PolkadotConfig};
+use subxt_signer::sr25519::dev;
+
+#[subxt::subxt(runtime_metadata_path = "../artifacts/polkadot_metadata_full.scale")]
+pub mod polkadot {}
+
+use polkadot::runtime_types::bounded_collections::bounded_vec::BoundedVec;
+use polkadot::runtime_types::frame_support::dispatch::RawOrigin;
+use polkadot::runtime_types::frame_support::traits::preimages::Bounded;
+use polkadot::runtime_types::frame_support::traits::schedule::DispatchTime;
+use polkadot::runtime_types::frame_system::pallet::Call as SystemCall;
+use polkadot::runtime_types::polkadot_runtime::OriginCaller;
+
+/// Calls up to this many bytes long can be placed inline in a proposal,
+/// rather than needing their preimage to be noted first.
+const MAX_INLINE_LEN: usize = 128;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Create a new API client, configured to talk to Polkadot nodes.
+    let api = OnlineClient::<PolkadotConfig>::new().await?;
+    let proposer = dev::alice();
+
+    // Build the call that we'd like the referendum to enact, using the
+    // generated `Call` type which covers every call in the runtime:
+    let call = polkadot::Call::System(SystemCall::remark_with_event {
+        remark: b"Proposed with subxt".to_vec(),
+    });
+
+    // The preimage of a proposal is the SCALE encoded call, and the proposal
+    // refers to it by its hash.
+    let preimage = call.encode();
+    let preimage_hash = BlakeTwo256::hash(&preimage);
+    println!("Preimage: 0x{}", hex::encode(&preimage));
+    println!("Preimage hash: {preimage_hash:?}");
+
+    // Small calls can be inlined into the proposal. Larger ones need their
+    // preimage to be noted on chain first, and are then looked up by hash.
+    let proposal = if preimage.len() <= MAX_INLINE_LEN {
+        Bounded::Inline(BoundedVec(preimage))
+    } else {
+        let len = preimage.len() as u32;
+        let note_preimage_tx = polkadot::tx().preimage().note_preimage(preimage);
+        api.tx()
+            .sign_and_submit_then_watch_default(&note_preimage_tx, &proposer)
+            .await?
+            .wait_for_finalized_success()
+            .await?;
+        println!("Preimage noted");
+
+        Bounded::Lookup {
+            hash: preimage_hash,
+            len,
+        }
+    };
+
+    // Submit the proposal on the root track, to be enacted 10 blocks after it's approved:
+    let submit_tx = polkadot::tx().referenda().submit(
+        OriginCaller::system(RawOrigin::Root),
+        proposal,
+        DispatchTime::After(10),
+    );
+    let events = api
+        .tx()
+        .sign_and_submit_then_watch_default(&submit_tx, &proposer)
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    if let Some(submitted) = events.find_first::<polkadot::referenda::events::Submitted>()? {
+        println!("Referendum {} submitted", submitted.index);
+    }
+
+    Ok(())
+}
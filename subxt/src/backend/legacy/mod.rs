@@ -12,7 +12,7 @@ use crate::backend::{
     rpc::RpcClient, Backend, BlockRef, RuntimeVersion, StorageResponse, StreamOf, StreamOfResults,
     TransactionStatus,
 };
-use crate::{config::Header, Config, Error};
+use crate::{config::Header, error::RpcError, Config, Error};
 use async_trait::async_trait;
 use futures::{future, future::Either, stream, Future, FutureExt, Stream, StreamExt};
 use std::collections::VecDeque;
@@ -257,10 +257,21 @@ impl<T: Config + Send + Sync + 'static> Backend<T> for LegacyBackend<T> {
         &self,
         extrinsic: &[u8],
     ) -> Result<StreamOfResults<TransactionStatus<T::Hash>>, Error> {
-        let sub = self
+        let sub = match self
             .methods
             .author_submit_and_watch_extrinsic(extrinsic)
-            .await?;
+            .await
+        {
+            Ok(sub) => sub,
+            // Clients which can't subscribe (eg those talking to a node over HTTP) can still
+            // submit the transaction; we just can't follow its progress beyond that.
+            Err(Error::Rpc(RpcError::SubscriptionsNotSupported)) => {
+                self.methods.author_submit_extrinsic(extrinsic).await?;
+                let sub = stream::once(future::ready(Ok(TransactionStatus::Validated)));
+                return Ok(StreamOf(Box::pin(sub)));
+            }
+            Err(e) => return Err(e),
+        };
         let sub = sub.filter_map(|r| {
             let mapped = r
                 .map(|tx| {
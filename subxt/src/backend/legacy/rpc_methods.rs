@@ -5,6 +5,7 @@
 //! An interface to call the raw legacy RPC methods.
 
 use crate::backend::rpc::{rpc_params, RpcClient, RpcSubscription};
+use crate::error::RpcError;
 use crate::metadata::Metadata;
 use crate::{Config, Error};
 use codec::Decode;
@@ -91,6 +92,100 @@ impl<T: Config> LegacyRpcMethods<T> {
             .map_err(Into::into)
     }
 
+    /// Fetch the raw bytes for the given key in some child trie.
+    pub async fn childstate_get_storage(
+        &self,
+        child_key: &ChildStorageKey,
+        key: &[u8],
+        at: Option<T::Hash>,
+    ) -> Result<Option<StorageData>, Error> {
+        let params = rpc_params![to_hex(child_key.as_bytes()), to_hex(key), at];
+        let data: Option<Bytes> = self.client.request("childstate_getStorage", params).await?;
+        Ok(data.map(|b| b.0))
+    }
+
+    /// Fetch the raw bytes for each of the given keys in some child trie.
+    pub async fn childstate_get_storage_entries(
+        &self,
+        child_key: &ChildStorageKey,
+        keys: impl IntoIterator<Item = &[u8]>,
+        at: Option<T::Hash>,
+    ) -> Result<Vec<Option<StorageData>>, Error> {
+        let keys: Vec<String> = keys.into_iter().map(to_hex).collect();
+        let params = rpc_params![to_hex(child_key.as_bytes()), keys, at];
+        let data: Vec<Option<Bytes>> = self
+            .client
+            .request("childstate_getStorageEntries", params)
+            .await?;
+        Ok(data.into_iter().map(|d| d.map(|b| b.0)).collect())
+    }
+
+    /// Fetch the hash of the value at the given key in some child trie.
+    pub async fn childstate_get_storage_hash(
+        &self,
+        child_key: &ChildStorageKey,
+        key: &[u8],
+        at: Option<T::Hash>,
+    ) -> Result<Option<T::Hash>, Error> {
+        let params = rpc_params![to_hex(child_key.as_bytes()), to_hex(key), at];
+        self.client
+            .request("childstate_getStorageHash", params)
+            .await
+    }
+
+    /// Fetch the size in bytes of the value at the given key in some child trie.
+    pub async fn childstate_get_storage_size(
+        &self,
+        child_key: &ChildStorageKey,
+        key: &[u8],
+        at: Option<T::Hash>,
+    ) -> Result<Option<u64>, Error> {
+        let params = rpc_params![to_hex(child_key.as_bytes()), to_hex(key), at];
+        self.client
+            .request("childstate_getStorageSize", params)
+            .await
+    }
+
+    /// Returns every key in some child trie which starts with the given prefix. This
+    /// is unbounded; prefer [`LegacyRpcMethods::childstate_get_keys_paged()`] for large
+    /// child tries.
+    pub async fn childstate_get_keys(
+        &self,
+        child_key: &ChildStorageKey,
+        prefix: &[u8],
+        at: Option<T::Hash>,
+    ) -> Result<Vec<StorageKey>, Error> {
+        let params = rpc_params![to_hex(child_key.as_bytes()), to_hex(prefix), at];
+        let data: Vec<Bytes> = self.client.request("childstate_getKeys", params).await?;
+        Ok(data.into_iter().map(|b| b.0).collect())
+    }
+
+    /// Returns the keys with prefix in some child trie, with pagination support.
+    /// Up to `count` keys will be returned.
+    /// If `start_key` is passed, return next keys in storage in lexicographic order.
+    pub async fn childstate_get_keys_paged(
+        &self,
+        child_key: &ChildStorageKey,
+        prefix: &[u8],
+        count: u32,
+        start_key: Option<&[u8]>,
+        at: Option<T::Hash>,
+    ) -> Result<Vec<StorageKey>, Error> {
+        let start_key = start_key.map(to_hex);
+        let params = rpc_params![
+            to_hex(child_key.as_bytes()),
+            to_hex(prefix),
+            count,
+            start_key,
+            at
+        ];
+        let data: Vec<Bytes> = self
+            .client
+            .request("childstate_getKeysPaged", params)
+            .await?;
+        Ok(data.into_iter().map(|b| b.0).collect())
+    }
+
     /// Fetch the genesis hash
     pub async fn genesis_hash(&self) -> Result<T::Hash, Error> {
         let block_zero = 0u32;
@@ -115,6 +210,11 @@ impl<T: Config> LegacyRpcMethods<T> {
         self.client.request("system_health", rpc_params![]).await
     }
 
+    /// Fetch the peers that the node is currently connected to.
+    pub async fn system_peers(&self) -> Result<Vec<PeerInfo<T::Hash>>, Error> {
+        self.client.request("system_peers", rpc_params![]).await
+    }
+
     /// Fetch system chain
     pub async fn system_chain(&self) -> Result<String, Error> {
         self.client.request("system_chain", rpc_params![]).await
@@ -137,6 +237,32 @@ impl<T: Config> LegacyRpcMethods<T> {
             .await
     }
 
+    /// Fetch the state of the node's block synchronization.
+    pub async fn system_sync_state(&self) -> Result<SyncState, Error> {
+        self.client.request("system_syncState", rpc_params![]).await
+    }
+
+    /// Wait until the node reports that it has caught up with the highest block
+    /// that it knows of (see [`SyncState::is_synced()`]), returning the [`SyncState`]
+    /// at that point. The sync state is checked straight away, and then again each
+    /// time a new best block is imported.
+    pub async fn wait_until_synced(&self) -> Result<SyncState, Error> {
+        // Subscribe first so that we don't miss any blocks between checks.
+        let mut new_heads = self.chain_subscribe_new_heads().await?;
+        loop {
+            let sync_state = self.system_sync_state().await?;
+            let health = self.system_health().await?;
+            if sync_state.is_synced(&health) {
+                return Ok(sync_state);
+            }
+            match new_heads.next().await {
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => return Err(e),
+                None => return Err(RpcError::SubscriptionDropped.into()),
+            }
+        }
+    }
+
     /// Fetch next nonce for an Account
     ///
     /// Return account nonce adjusted for extrinsics currently in transaction pool
@@ -169,6 +295,21 @@ impl<T: Config> LegacyRpcMethods<T> {
         Ok(block_hash)
     }
 
+    /// Get the hashes of each of the given block numbers in a single request, in the order
+    /// given. Hashes are `None` for blocks which don't exist yet.
+    ///
+    /// A range of blocks can be fetched with eg
+    /// `rpc.chain_get_block_hashes((0..10u64).map(Into::into))`.
+    pub async fn chain_get_block_hashes(
+        &self,
+        block_numbers: impl IntoIterator<Item = BlockNumber>,
+    ) -> Result<Vec<Option<T::Hash>>, Error> {
+        let block_numbers: Vec<_> = block_numbers.into_iter().collect();
+        let params = rpc_params![block_numbers];
+        let block_hashes = self.client.request("chain_getBlockHash", params).await?;
+        Ok(block_hashes)
+    }
+
     /// Get a block hash of the latest finalized block
     pub async fn chain_get_finalized_head(&self) -> Result<T::Hash, Error> {
         let hash = self
@@ -214,6 +355,25 @@ impl<T: Config> LegacyRpcMethods<T> {
         Ok(proof)
     }
 
+    /// Re-execute the given block with tracing enabled, returning the spans and events that
+    /// were recorded. This requires the node to have been built with runtime tracing enabled.
+    ///
+    /// - `targets` is a comma separated list of tracing targets to record, for instance
+    ///   `"pallet,frame,state"`. All targets are recorded if this is `None`.
+    /// - `storage_keys` is a comma separated list of hex encoded storage keys (without a
+    ///   `0x` prefix). Only storage events involving these keys are recorded.
+    /// - `methods` is a comma separated list of RPC methods to trace.
+    pub async fn state_trace_block(
+        &self,
+        hash: T::Hash,
+        targets: Option<&str>,
+        storage_keys: Option<&str>,
+        methods: Option<&str>,
+    ) -> Result<TraceBlockResponse, Error> {
+        let params = rpc_params![hash, targets, storage_keys, methods];
+        self.client.request("state_traceBlock", params).await
+    }
+
     /// Fetch the runtime version
     pub async fn state_get_runtime_version(
         &self,
@@ -281,6 +441,37 @@ impl<T: Config> LegacyRpcMethods<T> {
         Ok(subscription)
     }
 
+    /// Subscribe to GRANDPA justifications as they are produced. Each item is a SCALE
+    /// encoded justification for some newly finalized block.
+    pub async fn grandpa_subscribe_justifications(&self) -> Result<RpcSubscription<Bytes>, Error> {
+        let subscription = self
+            .client
+            .subscribe(
+                "grandpa_subscribeJustifications",
+                rpc_params![],
+                "grandpa_unsubscribeJustifications",
+            )
+            .await?;
+        Ok(subscription)
+    }
+
+    /// Fetch a proof that the given block is finalized, which can be checked against the
+    /// GRANDPA authority set. Returns `None` if the node is unable to prove the finality of
+    /// the block, for instance because it has not been finalized yet.
+    pub async fn grandpa_prove_finality(
+        &self,
+        block_number: BlockNumber,
+    ) -> Result<Option<FinalityProof<T::Hash, T::Header>>, Error> {
+        let bytes: Option<Bytes> = self
+            .client
+            .request("grandpa_proveFinality", rpc_params![block_number])
+            .await?;
+        let proof = bytes
+            .map(|b| FinalityProof::decode(&mut &b.0[..]))
+            .transpose()?;
+        Ok(proof)
+    }
+
     /// Subscribe to runtime version updates that produce changes in the metadata.
     /// The first item emitted by the stream is the current runtime version.
     pub async fn state_subscribe_runtime_version(
@@ -297,6 +488,72 @@ impl<T: Config> LegacyRpcMethods<T> {
         Ok(subscription)
     }
 
+    /// Subscribe to changes to the storage entries at the given keys. The node filters
+    /// the changes, and so only [`StorageChangeSet`]s involving the given keys are
+    /// sent back. The first item emitted by the stream contains the current value
+    /// of each key. If no keys are given, every storage change is reported (nodes
+    /// often consider this to be an unsafe RPC call).
+    ///
+    /// **Note:** Nodes only match the exact keys given here; to follow every entry under
+    /// some prefix, use [`Self::state_subscribe_storage_prefixes()`].
+    pub async fn state_subscribe_storage(
+        &self,
+        keys: impl IntoIterator<Item = &[u8]>,
+    ) -> Result<RpcSubscription<StorageChangeSet<T::Hash>>, Error> {
+        let keys: Vec<String> = keys.into_iter().map(to_hex).collect();
+        let keys = (!keys.is_empty()).then_some(keys);
+        let subscription = self
+            .client
+            .subscribe(
+                "state_subscribeStorage",
+                rpc_params![keys],
+                "state_unsubscribeStorage",
+            )
+            .await?;
+        Ok(subscription)
+    }
+
+    /// Subscribe to changes to the storage entries under any of the given key prefixes. The
+    /// keys currently under each prefix are looked up at the given block (or the best block if
+    /// none is given) using [`Self::state_get_keys_paged()`], and then exactly those keys are
+    /// subscribed to via [`Self::state_subscribe_storage()`]. This means that the node filters
+    /// the changes, rather than sending back every storage change.
+    ///
+    /// **Note:** Entries created under a prefix after this is called are not included; call
+    /// this again to pick them up. An error is returned if there are no keys under any of the
+    /// prefixes, since subscribing to no keys at all would report every storage change.
+    pub async fn state_subscribe_storage_prefixes(
+        &self,
+        prefixes: impl IntoIterator<Item = &[u8]>,
+        at: Option<T::Hash>,
+    ) -> Result<RpcSubscription<StorageChangeSet<T::Hash>>, Error> {
+        const KEYS_PER_PAGE: u32 = 1000;
+
+        let mut keys = Vec::new();
+        for prefix in prefixes {
+            let mut start_key: Option<StorageKey> = None;
+            loop {
+                let page = self
+                    .state_get_keys_paged(prefix, KEYS_PER_PAGE, start_key.as_deref(), at)
+                    .await?;
+                let is_last_page = page.len() < KEYS_PER_PAGE as usize;
+                start_key = page.last().cloned();
+                keys.extend(page);
+                if is_last_page {
+                    break;
+                }
+            }
+        }
+
+        if keys.is_empty() {
+            return Err(Error::Other(
+                "No storage keys exist under the given prefixes to subscribe to".to_owned(),
+            ));
+        }
+        self.state_subscribe_storage(keys.iter().map(|k| &k[..]))
+            .await
+    }
+
     /// Create and submit an extrinsic and return corresponding Hash if successful
     pub async fn author_submit_extrinsic(&self, extrinsic: &[u8]) -> Result<T::Hash, Error> {
         let params = rpc_params![to_hex(extrinsic)];
@@ -367,6 +624,77 @@ impl<T: Config> LegacyRpcMethods<T> {
         self.client.request("author_hasKey", params).await
     }
 
+    /// Return the SCALE encoded bytes of each extrinsic that is waiting in the node's
+    /// transaction pool to be included in a block.
+    pub async fn author_pending_extrinsics(&self) -> Result<Vec<Vec<u8>>, Error> {
+        let xts: Vec<Bytes> = self
+            .client
+            .request("author_pendingExtrinsics", rpc_params![])
+            .await?;
+        Ok(xts.into_iter().map(|b| b.0).collect())
+    }
+
+    /// Remove the given extrinsics from the node's transaction pool, along with any
+    /// extrinsics which depend on them. Each extrinsic can be given either by its hash
+    /// or by its SCALE encoded bytes.
+    ///
+    /// Returns the hashes of every extrinsic that was removed.
+    pub async fn author_remove_extrinsic(
+        &self,
+        to_remove: Vec<ExtrinsicOrHash<T::Hash>>,
+    ) -> Result<Vec<T::Hash>, Error> {
+        self.client
+            .request("author_removeExtrinsic", rpc_params![to_remove])
+            .await
+    }
+
+    /// Set a value in the node's off-chain local storage, where it can be read by off-chain workers.
+    pub async fn offchain_local_storage_set(
+        &self,
+        kind: OffchainStorageKind,
+        key: &[u8],
+        value: &[u8],
+    ) -> Result<(), Error> {
+        let params = rpc_params![kind, to_hex(key), to_hex(value)];
+        self.client
+            .request("offchain_localStorageSet", params)
+            .await
+    }
+
+    /// Fetch a value from the node's off-chain local storage.
+    pub async fn offchain_local_storage_get(
+        &self,
+        kind: OffchainStorageKind,
+        key: &[u8],
+    ) -> Result<Option<Vec<u8>>, Error> {
+        let params = rpc_params![kind, to_hex(key)];
+        let data: Option<Bytes> = self
+            .client
+            .request("offchain_localStorageGet", params)
+            .await?;
+        Ok(data.map(|b| b.0))
+    }
+
+    /// Fetch the slots in the current epoch that each BABE authority in the node's keystore
+    /// is able to author blocks in, keyed by the SS58 encoded authority ID.
+    pub async fn babe_epoch_authorship(
+        &self,
+    ) -> Result<std::collections::HashMap<String, EpochAuthorship>, Error> {
+        self.client
+            .request("babe_epochAuthorship", rpc_params![])
+            .await
+    }
+
+    /// Generate the chain spec of the chain that the node is running, along with the node's
+    /// current light sync state, which a light client can use to sync from this point
+    /// rather than from genesis. If `raw` is true, the genesis state in the chain spec is
+    /// given as raw storage keys and values.
+    pub async fn sync_state_gen_sync_spec(&self, raw: bool) -> Result<serde_json::Value, Error> {
+        self.client
+            .request("sync_state_genSyncSpec", rpc_params![raw])
+            .await
+    }
+
     /// Execute a runtime API call via `state_call` RPC method.
     pub async fn state_call(
         &self,
@@ -397,6 +725,29 @@ impl<T: Config> LegacyRpcMethods<T> {
         let result_bytes: Bytes = self.client.request("system_dryRun", params).await?;
         Ok(DryRunResultBytes(result_bytes.0))
     }
+
+    /// Query the weight, dispatch class and partial fee (ie the fee less any tip) of the
+    /// given encoded extrinsic, at the given block (or the best block if none is given).
+    /// The extrinsic should be signed, since the fee depends on its encoded length.
+    pub async fn payment_query_info(
+        &self,
+        encoded_signed: &[u8],
+        at: Option<T::Hash>,
+    ) -> Result<RuntimeDispatchInfo, Error> {
+        let params = rpc_params![to_hex(encoded_signed), at];
+        self.client.request("payment_queryInfo", params).await
+    }
+
+    /// Query a breakdown of the inclusion fee of the given encoded extrinsic, at the given
+    /// block (or the best block if none is given). Unsigned extrinsics have no inclusion fee.
+    pub async fn payment_query_fee_details(
+        &self,
+        encoded_signed: &[u8],
+        at: Option<T::Hash>,
+    ) -> Result<FeeDetails, Error> {
+        let params = rpc_params![to_hex(encoded_signed), at];
+        self.client.request("payment_queryFeeDetails", params).await
+    }
 }
 
 /// Storage key.
@@ -405,6 +756,34 @@ pub type StorageKey = Vec<u8>;
 /// Storage data.
 pub type StorageData = Vec<u8>;
 
+/// The key of a child trie, as expected by the `childstate_*` RPC methods. This is the
+/// unique identifier of the child trie, prefixed with the type of child trie that it is.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ChildStorageKey(Vec<u8>);
+
+impl ChildStorageKey {
+    /// The prefix given to the keys of default child tries.
+    pub const DEFAULT_PREFIX: &'static [u8] = b":child_storage:default:";
+
+    /// The key of the default child trie with the given unique identifier (for instance,
+    /// the trie ID of a contract).
+    pub fn default_child_trie(id: impl AsRef<[u8]>) -> Self {
+        let mut key = Self::DEFAULT_PREFIX.to_vec();
+        key.extend_from_slice(id.as_ref());
+        ChildStorageKey(key)
+    }
+
+    /// A child trie key which already includes its prefix.
+    pub fn from_prefixed_key(key: Vec<u8>) -> Self {
+        ChildStorageKey(key)
+    }
+
+    /// The bytes of the key, including its prefix.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 /// Health struct returned by the RPC
 #[derive(Deserialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -419,6 +798,49 @@ pub struct SystemHealth {
     pub should_have_peers: bool,
 }
 
+/// Information about a peer that the node is connected to, as returned by `system_peers`.
+///
+/// # Note
+///
+/// This is copied from `sc-rpc-api` to avoid a dependency on that crate. Therefore it
+/// must be kept compatible with that type from the target substrate version.
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerInfo<Hash> {
+    /// The peer ID.
+    pub peer_id: String,
+    /// The roles of the peer, for instance `"FULL"` or `"AUTHORITY"`.
+    pub roles: String,
+    /// The hash of the best block that the peer has.
+    pub best_hash: Hash,
+    /// The number of the best block that the peer has.
+    pub best_number: u64,
+}
+
+/// The state of a node's block synchronization, as returned by `system_syncState`.
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncState {
+    /// Height of the block at which syncing started.
+    pub starting_block: u64,
+    /// Height of the current best block of the node.
+    pub current_block: u64,
+    /// Height of the highest block in the network that the node knows of.
+    pub highest_block: u64,
+}
+
+impl SyncState {
+    /// Has the node caught up with the highest block that it knows of? This needs the
+    /// node's [`SystemHealth`] too, because a node which has only just started doesn't
+    /// know of any blocks beyond its own, and so looks caught up until it finds some peers
+    /// to sync from. The node is only considered synced if it isn't syncing and, unless
+    /// it's not expected to have any (for instance, on a local dev chain), it has peers.
+    pub fn is_synced(&self, health: &SystemHealth) -> bool {
+        let has_peers = health.peers > 0 || !health.should_have_peers;
+        !health.is_syncing && has_peers && self.current_block >= self.highest_block
+    }
+}
+
 /// System properties; an arbitrary JSON object.
 pub type SystemProperties = serde_json::Map<String, serde_json::Value>;
 
@@ -483,7 +905,7 @@ pub struct RuntimeVersion {
 ///
 /// This is copied from `sp-transaction-pool` to avoid a dependency on that crate. Therefore it
 /// must be kept compatible with that type from the target substrate version.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum TransactionStatus<Hash> {
     /// Transaction is part of the future queue.
@@ -510,6 +932,55 @@ pub enum TransactionStatus<Hash> {
     Invalid,
 }
 
+/// An extrinsic to remove from the transaction pool via
+/// [`LegacyRpcMethods::author_remove_extrinsic()`].
+///
+/// # Note
+///
+/// This is copied from `sc-rpc-api` to avoid a dependency on that crate. Therefore it
+/// must be kept compatible with that type from the target substrate version.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExtrinsicOrHash<Hash> {
+    /// The hash of the extrinsic.
+    Hash(Hash),
+    /// The SCALE encoded extrinsic bytes.
+    Extrinsic(Bytes),
+}
+
+/// The slots in an epoch which a BABE authority can author blocks in, as returned by
+/// `babe_epochAuthorship`.
+///
+/// # Note
+///
+/// This is copied from `sc-consensus-babe-rpc` to avoid a dependency on that crate. Therefore it
+/// must be kept compatible with that type from the target substrate version.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EpochAuthorship {
+    /// Slots in which the authority is the primary block author.
+    pub primary: Vec<u64>,
+    /// Slots in which the authority is a secondary (plain) block author.
+    pub secondary: Vec<u64>,
+    /// Slots in which the authority is a secondary (VRF) block author.
+    pub secondary_vrf: Vec<u64>,
+}
+
+/// The kind of off-chain local storage to read from or write to.
+///
+/// # Note
+///
+/// This is copied from `sp-core` to avoid a dependency on that crate. Therefore it
+/// must be kept compatible with that type from the target substrate version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OffchainStorageKind {
+    /// Storage which persists across forks, and is shared between off-chain worker runs.
+    #[serde(rename = "PERSISTENT")]
+    Persistent,
+    /// Storage which is local to the fork that an off-chain worker is running on.
+    #[serde(rename = "LOCAL")]
+    Local,
+}
+
 /// The decoded result returned from calling `system_dryRun` on some extrinsic.
 #[derive(Debug, PartialEq, Eq)]
 pub enum DryRunResult {
@@ -559,6 +1030,88 @@ impl DryRunResultBytes {
     }
 }
 
+/// Weight and fee information about an extrinsic, returned by the `payment_queryInfo` RPC.
+///
+/// # Note
+///
+/// This is copied from `pallet-transaction-payment` to avoid a dependency on that crate. Therefore it
+/// must be kept compatible with that type from the target substrate version.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeDispatchInfo {
+    /// The weight of the extrinsic.
+    pub weight: Weight,
+    /// The class of the extrinsic.
+    pub class: DispatchClass,
+    /// The inclusion fee of the extrinsic, without any tip.
+    #[serde(with = "serde_balance")]
+    pub partial_fee: u128,
+}
+
+/// The weight of an extrinsic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Weight {
+    /// The computational time used to execute some logic.
+    #[serde(alias = "refTime")]
+    pub ref_time: u64,
+    /// The size of the storage proof needed to execute some logic.
+    #[serde(alias = "proofSize")]
+    pub proof_size: u64,
+}
+
+/// The class of an extrinsic, which determines how its weight is accounted for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DispatchClass {
+    /// A normal extrinsic, such as a user transaction.
+    Normal,
+    /// An operational extrinsic, which can use the block's reserved space.
+    Operational,
+    /// A mandatory extrinsic, which is always included in a block (eg inherents).
+    Mandatory,
+}
+
+/// A breakdown of the fee of an extrinsic, returned by the `payment_queryFeeDetails` RPC.
+///
+/// # Note
+///
+/// This is copied from `pallet-transaction-payment` to avoid a dependency on that crate. Therefore it
+/// must be kept compatible with that type from the target substrate version.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeDetails {
+    /// The fee paid for the extrinsic to be included in a block, if it is signed.
+    pub inclusion_fee: Option<InclusionFee>,
+}
+
+/// The components of the fee paid for an extrinsic to be included in a block. The inclusion
+/// fee is `base_fee + len_fee + adjusted_weight_fee`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionFee {
+    /// The minimum fee paid by every extrinsic.
+    pub base_fee: NumberOrHex,
+    /// The fee due to the encoded length of the extrinsic.
+    pub len_fee: NumberOrHex,
+    /// The fee due to the weight of the extrinsic, adjusted by the current fee multiplier.
+    pub adjusted_weight_fee: NumberOrHex,
+}
+
+/// Balances are serialized as decimal strings in RPC responses, to avoid overflows
+/// in JavaScript clients.
+mod serde_balance {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(balance: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&balance.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 /// Storage change set
 #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -604,6 +1157,102 @@ pub struct ReadProof<Hash> {
     pub proof: Vec<Bytes>,
 }
 
+/// The response from the `state_traceBlock` RPC.
+///
+/// # Note
+///
+/// This and the types it contains are copied from `sp-rpc` to avoid a dependency on that
+/// crate. Therefore they must be kept compatible with those types from the target substrate version.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TraceBlockResponse {
+    /// An error occurred while tracing the block.
+    TraceError(TraceError),
+    /// The trace of the block.
+    BlockTrace(BlockTrace),
+}
+
+/// An error which occurred while tracing a block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceError {
+    /// A description of the error.
+    pub error: String,
+}
+
+/// The spans and events recorded while executing a block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockTrace {
+    /// Hash of the block being traced.
+    pub block_hash: String,
+    /// Parent hash of the block being traced.
+    pub parent_hash: String,
+    /// The tracing targets that were recorded.
+    pub tracing_targets: String,
+    /// The storage keys that events were filtered by.
+    pub storage_keys: String,
+    /// The RPC methods that were traced.
+    pub methods: String,
+    /// The spans recorded, each representing some period of execution.
+    pub spans: Vec<TraceSpan>,
+    /// The events recorded, such as storage reads and writes.
+    pub events: Vec<TraceEvent>,
+}
+
+/// A span recorded while tracing a block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceSpan {
+    /// The ID of this span.
+    pub id: u64,
+    /// The ID of the span that this one is nested in, if any.
+    pub parent_id: Option<u64>,
+    /// The name of the span.
+    pub name: String,
+    /// The tracing target of the span.
+    pub target: String,
+    /// Was the span recorded from within the Wasm runtime?
+    pub wasm: bool,
+}
+
+/// An event recorded while tracing a block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceEvent {
+    /// The tracing target of the event.
+    pub target: String,
+    /// The values recorded with the event.
+    pub data: TraceEventData,
+    /// The ID of the span that this event was recorded in, if any.
+    pub parent_id: Option<u64>,
+}
+
+/// The values recorded with a [`TraceEvent`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceEventData {
+    /// Each of the values recorded, by name.
+    pub string_values: std::collections::HashMap<String, String>,
+}
+
+/// A proof of finality returned by the `grandpa_proveFinality` RPC.
+///
+/// # Note
+///
+/// This is copied from `sc-consensus-grandpa` to avoid a dependency on that crate. Therefore it
+/// must be kept compatible with that type from the target substrate version.
+#[derive(Clone, Debug, PartialEq, Eq, Decode)]
+pub struct FinalityProof<Hash, Header> {
+    /// The hash of the block that the justification finalizes.
+    pub block: Hash,
+    /// The SCALE encoded GRANDPA justification for the block.
+    pub justification: Vec<u8>,
+    /// The headers between the block whose finality was requested and the finalized block,
+    /// which a light client may not know of.
+    pub unknown_headers: Vec<Header>,
+}
+
 /// A number type that can be serialized both as a number or a string that encodes a number in a
 /// string.
 ///
@@ -681,3 +1330,58 @@ impl From<Vec<u8>> for Bytes {
         Bytes(s)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sync_state_is_synced() {
+        let caught_up = SyncState {
+            starting_block: 0,
+            current_block: 10,
+            highest_block: 10,
+        };
+        let health = |peers, is_syncing, should_have_peers| SystemHealth {
+            peers,
+            is_syncing,
+            should_have_peers,
+        };
+
+        assert!(caught_up.is_synced(&health(5, false, true)));
+        // Local chains don't need peers to be synced:
+        assert!(caught_up.is_synced(&health(0, false, false)));
+        // A fresh node with no peers yet hasn't synced, even though it looks caught up:
+        assert!(!caught_up.is_synced(&health(0, false, true)));
+        // Nor has a node which reports that it's still syncing:
+        assert!(!caught_up.is_synced(&health(5, true, true)));
+
+        let behind = SyncState {
+            highest_block: 20,
+            ..caught_up
+        };
+        assert!(!behind.is_synced(&health(5, false, true)));
+    }
+
+    #[test]
+    fn epoch_authorship_deserializes() {
+        let json = r#"{
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY": {
+                "primary": [1],
+                "secondary": [],
+                "secondary_vrf": [2, 3]
+            }
+        }"#;
+
+        let authorship: std::collections::HashMap<String, EpochAuthorship> =
+            serde_json::from_str(json).unwrap();
+        assert_eq!(
+            authorship["5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"],
+            EpochAuthorship {
+                primary: vec![1],
+                secondary: vec![],
+                secondary_vrf: vec![2, 3],
+            }
+        );
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use super::retry_rpc_client::is_safe_to_retry;
+use super::{RawRpcFuture, RawRpcSubscription, RpcClient, RpcClientT};
+use crate::error::RpcError;
+use serde_json::value::RawValue;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// An RPC client which fails over between several other RPC clients, usually each connected
+/// to a different node. Requests and subscriptions are sent to the current client, and if
+/// that fails with a transient error (see [`RpcError::is_transient()`]), the next client is
+/// tried in turn and becomes the current one.
+///
+/// Requests which aren't safe to repeat are only sent to the current client, and are not
+/// failed over. These are methods which submit transactions or modify the node's keys
+/// (those in the `author_`, `transaction_` and `transactionWatch_` namespaces), since the
+/// first attempt may have taken effect even though we didn't hear back about it.
+///
+/// Subscriptions which have already been established are not moved to a new client if
+/// theirs fails. To have those survive a dropped connection too, use this with clients
+/// which reconnect themselves (see the `unstable-reconnecting-rpc-client` feature).
+///
+/// # Example
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() {
+/// use subxt::backend::rpc::{FailoverRpcClient, RpcClient};
+/// use subxt::{OnlineClient, PolkadotConfig};
+///
+/// let rpc_client = FailoverRpcClient::from_urls([
+///     "wss://rpc.example.com:443",
+///     "wss://rpc-backup.example.com:443",
+/// ])
+/// .await
+/// .unwrap();
+///
+/// let api = OnlineClient::<PolkadotConfig>::from_rpc_client(RpcClient::new(rpc_client))
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+pub struct FailoverRpcClient {
+    clients: Vec<RpcClient>,
+    current: AtomicUsize,
+}
+
+impl FailoverRpcClient {
+    /// Create a new [`FailoverRpcClient`] from the given clients, which are tried in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no clients are given.
+    pub fn new(clients: impl IntoIterator<Item = RpcClient>) -> Self {
+        let clients: Vec<_> = clients.into_iter().collect();
+        assert!(
+            !clients.is_empty(),
+            "FailoverRpcClient needs at least one client"
+        );
+        FailoverRpcClient {
+            clients,
+            current: AtomicUsize::new(0),
+        }
+    }
+
+    #[cfg(feature = "jsonrpsee")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "jsonrpsee")))]
+    /// Connect to each of the given URLs, and create a [`FailoverRpcClient`] from the
+    /// connections which succeed. URLs which can't be connected to are skipped, and an error
+    /// is only returned if none of them can be.
+    ///
+    /// Errors if an insecure URL is provided; see [`RpcClient::from_url`].
+    pub async fn from_urls<U: AsRef<str>>(
+        urls: impl IntoIterator<Item = U>,
+    ) -> Result<Self, crate::Error> {
+        let mut clients = Vec::new();
+        let mut last_err = None;
+        for url in urls {
+            match RpcClient::from_url(url).await {
+                Ok(client) => clients.push(client),
+                Err(e @ crate::Error::Rpc(RpcError::InsecureUrl(_))) => return Err(e),
+                Err(e) => last_err = Some(e),
+            }
+        }
+
+        if clients.is_empty() {
+            return Err(last_err.unwrap_or_else(|| "No RPC URLs were provided".into()));
+        }
+        Ok(FailoverRpcClient::new(clients))
+    }
+
+    /// The client that requests are currently being sent to.
+    fn current_client(&self) -> &RpcClient {
+        &self.clients[self.current.load(Ordering::Relaxed)]
+    }
+
+    /// Run the given function against the current client, failing over to each of the other
+    /// clients in turn if it fails with a transient error.
+    async fn with_failover<'a, R>(
+        &'a self,
+        f: impl Fn(&'a RpcClient) -> RawRpcFuture<'a, R> + Send,
+    ) -> Result<R, RpcError> {
+        let start = self.current.load(Ordering::Relaxed);
+        let mut last_err = None;
+
+        for n in 0..self.clients.len() {
+            let idx = (start + n) % self.clients.len();
+            match f(&self.clients[idx]).await {
+                Ok(res) => {
+                    self.current.store(idx, Ordering::Relaxed);
+                    return Ok(res);
+                }
+                Err(e) if e.is_transient() => {
+                    tracing::debug!("RPC client {idx} failed, trying the next one: {e}");
+                    last_err = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(last_err.expect("at least one client is always tried; qed"))
+    }
+}
+
+impl RpcClientT for FailoverRpcClient {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RawRpcFuture<'a, Box<RawValue>> {
+        if !is_safe_to_retry(method) {
+            return self.current_client().request_raw(method, params);
+        }
+        Box::pin(self.with_failover(move |client| client.request_raw(method, params.clone())))
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RawRpcFuture<'a, RawRpcSubscription> {
+        if !is_safe_to_retry(sub) {
+            return self.current_client().subscribe_raw(sub, params, unsub);
+        }
+        Box::pin(self.with_failover(move |client| client.subscribe_raw(sub, params.clone(), unsub)))
+    }
+
+    fn batch_request_raw<'a>(
+        &'a self,
+        requests: Vec<(&'a str, Option<Box<RawValue>>)>,
+    ) -> RawRpcFuture<'a, Vec<Result<Box<RawValue>, RpcError>>> {
+        if !requests.iter().all(|(method, _)| is_safe_to_retry(method)) {
+            return self.current_client().batch_request_raw(requests);
+        }
+        Box::pin(self.with_failover(move |client| client.batch_request_raw(requests.clone())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+
+    /// A client which either answers every request with its name, or fails them all.
+    struct MockClient {
+        name: &'static str,
+        up: Arc<AtomicBool>,
+    }
+
+    impl MockClient {
+        fn new(name: &'static str) -> (RpcClient, Arc<AtomicBool>) {
+            let up = Arc::new(AtomicBool::new(true));
+            let client = MockClient {
+                name,
+                up: up.clone(),
+            };
+            (RpcClient::new(client), up)
+        }
+    }
+
+    impl RpcClientT for MockClient {
+        fn request_raw<'a>(
+            &'a self,
+            method: &'a str,
+            _params: Option<Box<RawValue>>,
+        ) -> RawRpcFuture<'a, Box<RawValue>> {
+            Box::pin(async move {
+                if method == "bad_method" {
+                    return Err(RpcError::request_rejected("bad method"));
+                }
+                if !self.up.load(Ordering::Relaxed) {
+                    return Err(RpcError::SubscriptionDropped);
+                }
+                Ok(RawValue::from_string(format!("\"{}\"", self.name)).unwrap())
+            })
+        }
+
+        fn subscribe_raw<'a>(
+            &'a self,
+            _sub: &'a str,
+            _params: Option<Box<RawValue>>,
+            _unsub: &'a str,
+        ) -> RawRpcFuture<'a, RawRpcSubscription> {
+            Box::pin(async { Err(RpcError::SubscriptionsNotSupported) })
+        }
+    }
+
+    async fn request(client: &FailoverRpcClient, method: &str) -> Result<String, RpcError> {
+        let res = client.request_raw(method, None).await?;
+        Ok(serde_json::from_str(res.get()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn fails_over_on_connection_errors() {
+        let (a, a_up) = MockClient::new("a");
+        let (b, b_up) = MockClient::new("b");
+        let client = FailoverRpcClient::new([a, b]);
+
+        assert_eq!(request(&client, "foo").await.unwrap(), "a");
+
+        // When "a" goes down, "b" is used and then sticks:
+        a_up.store(false, Ordering::Relaxed);
+        assert_eq!(request(&client, "foo").await.unwrap(), "b");
+        a_up.store(true, Ordering::Relaxed);
+        assert_eq!(request(&client, "foo").await.unwrap(), "b");
+
+        // When "b" goes down, we go back around to "a":
+        b_up.store(false, Ordering::Relaxed);
+        assert_eq!(request(&client, "foo").await.unwrap(), "a");
+
+        // When both are down, the error is returned:
+        a_up.store(false, Ordering::Relaxed);
+        assert!(matches!(
+            request(&client, "foo").await,
+            Err(RpcError::SubscriptionDropped)
+        ));
+    }
+
+    #[tokio::test]
+    async fn does_not_fail_over_on_rejected_requests() {
+        let (a, _) = MockClient::new("a");
+        let (b, _) = MockClient::new("b");
+        let client = FailoverRpcClient::new([a, b]);
+
+        assert!(matches!(
+            request(&client, "bad_method").await,
+            Err(RpcError::RequestRejected(_))
+        ));
+        assert_eq!(request(&client, "foo").await.unwrap(), "a");
+    }
+
+    #[tokio::test]
+    async fn does_not_fail_over_unsafe_requests() {
+        let (a, a_up) = MockClient::new("a");
+        let (b, _) = MockClient::new("b");
+        let client = FailoverRpcClient::new([a, b]);
+
+        // Submitting a transaction to "a" fails, and isn't then sent to "b":
+        a_up.store(false, Ordering::Relaxed);
+        assert!(matches!(
+            request(&client, "author_submitExtrinsic").await,
+            Err(RpcError::SubscriptionDropped)
+        ));
+
+        // Safe requests still fail over, and unsafe ones then follow:
+        assert_eq!(request(&client, "foo").await.unwrap(), "b");
+        assert_eq!(
+            request(&client, "author_submitExtrinsic").await.unwrap(),
+            "b"
+        );
+    }
+}
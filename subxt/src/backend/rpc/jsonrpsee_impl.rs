@@ -7,7 +7,8 @@ use crate::error::RpcError;
 use futures::stream::{StreamExt, TryStreamExt};
 use jsonrpsee::{
     core::{
-        client::{Client, ClientT, SubscriptionClientT, SubscriptionKind},
+        client::{Client, ClientT, Error as ClientError, SubscriptionClientT, SubscriptionKind},
+        params::BatchRequestBuilder,
         traits::ToRpcParams,
     },
     types::SubscriptionId,
@@ -22,6 +23,37 @@ impl ToRpcParams for Params {
     }
 }
 
+/// Convert a jsonrpsee error into an [`RpcError`], keeping timeouts distinct.
+fn to_rpc_error(err: ClientError) -> RpcError {
+    match err {
+        ClientError::RequestTimeout => RpcError::Timeout,
+        err => RpcError::ClientError(Box::new(err)),
+    }
+}
+
+/// Send the given requests as a single JSON-RPC batch.
+async fn batch_request<C: ClientT + Sync>(
+    client: &C,
+    requests: Vec<(&str, Option<Box<RawValue>>)>,
+) -> Result<Vec<Result<Box<RawValue>, RpcError>>, RpcError> {
+    let mut batch = BatchRequestBuilder::new();
+    for (method, params) in requests {
+        batch
+            .insert(method, Params(params))
+            .map_err(|e| RpcError::ClientError(Box::new(e)))?;
+    }
+
+    let res = ClientT::batch_request::<Box<RawValue>>(client, batch)
+        .await
+        .map_err(to_rpc_error)?;
+
+    let res = res
+        .into_iter()
+        .map(|r| r.map_err(|e| RpcError::ClientError(Box::new(ClientError::Call(e.into_owned())))))
+        .collect();
+    Ok(res)
+}
+
 impl RpcClientT for Client {
     fn request_raw<'a>(
         &'a self,
@@ -31,7 +63,7 @@ impl RpcClientT for Client {
         Box::pin(async move {
             let res = ClientT::request(self, method, Params(params))
                 .await
-                .map_err(|e| RpcError::ClientError(Box::new(e)))?;
+                .map_err(to_rpc_error)?;
             Ok(res)
         })
     }
@@ -50,7 +82,7 @@ impl RpcClientT for Client {
                 unsub,
             )
             .await
-            .map_err(|e| RpcError::ClientError(Box::new(e)))?;
+            .map_err(to_rpc_error)?;
 
             let id = match stream.kind() {
                 SubscriptionKind::Subscription(SubscriptionId::Str(id)) => {
@@ -59,10 +91,49 @@ impl RpcClientT for Client {
                 _ => None,
             };
 
-            let stream = stream
-                .map_err(|e| RpcError::ClientError(Box::new(e)))
-                .boxed();
+            let stream = stream.map_err(to_rpc_error).boxed();
             Ok(RawRpcSubscription { stream, id })
         })
     }
+
+    fn batch_request_raw<'a>(
+        &'a self,
+        requests: Vec<(&'a str, Option<Box<RawValue>>)>,
+    ) -> RawRpcFuture<'a, Vec<Result<Box<RawValue>, RpcError>>> {
+        Box::pin(batch_request(self, requests))
+    }
+}
+
+#[cfg(feature = "native")]
+impl RpcClientT for jsonrpsee::http_client::HttpClient {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RawRpcFuture<'a, Box<RawValue>> {
+        Box::pin(async move {
+            let res = ClientT::request(self, method, Params(params))
+                .await
+                .map_err(to_rpc_error)?;
+            Ok(res)
+        })
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        _sub: &'a str,
+        _params: Option<Box<RawValue>>,
+        _unsub: &'a str,
+    ) -> RawRpcFuture<'a, RawRpcSubscription> {
+        // HTTP is a request/response transport, so the node has no way to push
+        // subscription notifications back to us.
+        Box::pin(async move { Err(RpcError::SubscriptionsNotSupported) })
+    }
+
+    fn batch_request_raw<'a>(
+        &'a self,
+        requests: Vec<(&'a str, Option<Box<RawValue>>)>,
+    ) -> RawRpcFuture<'a, Vec<Result<Box<RawValue>, RpcError>>> {
+        Box::pin(batch_request(self, requests))
+    }
 }
@@ -0,0 +1,464 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use super::{RawRpcFuture, RawRpcSubscription, RpcClientT};
+use crate::{
+    backend::legacy::rpc_methods::{Bytes, TransactionStatus},
+    error::{Error, RpcError},
+    events::{Phase, StaticEvent},
+    metadata::Metadata,
+    storage::Address,
+};
+use codec::{Compact, Encode};
+use futures::StreamExt;
+use scale_encode::{EncodeAsFields, EncodeAsType};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::value::RawValue;
+use std::collections::HashMap;
+
+type MethodHandler = Box<dyn Fn(MockParams) -> Result<Box<RawValue>, RpcError> + Send + Sync>;
+type SubscriptionHandler =
+    Box<dyn Fn(MockParams) -> Result<Vec<Box<RawValue>>, RpcError> + Send + Sync>;
+
+/// An [`RpcClientT`] implementation which doesn't talk to a node at all, and instead
+/// responds to requests and subscriptions using handlers that have been given to it.
+/// This allows code which uses Subxt to be tested without a running node.
+///
+/// Each handler is given the parameters of the call, and returns the response that a node
+/// would. Requests to methods that have no handler fail with [`RpcError::RequestRejected`].
+///
+/// To use it with an [`crate::OnlineClient`], construct the client with
+/// [`crate::OnlineClient::from_rpc_client_with`], which takes the metadata, genesis hash and
+/// runtime version up front so that these don't need handlers. The legacy RPC methods (see
+/// [`crate::backend::legacy::LegacyRpcMethods`]) are then used to talk to the mock client; for
+/// example, storage is fetched via `state_getStorage`, and transactions are submitted and
+/// watched via `author_submitAndWatchExtrinsic`. Rather than writing handlers for these by
+/// hand, [`MockRpcClientBuilder::with_storage()`], [`MockRpcClientBuilder::with_events()`]
+/// and [`MockRpcClientBuilder::with_tx_outcome()`] can be used to provide typed values which
+/// are encoded and handed back as needed.
+///
+/// # Example
+///
+/// ```rust
+/// use futures::StreamExt;
+/// use subxt::backend::rpc::{MockRpcClient, RpcClient, rpc_params};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mock_client = MockRpcClient::builder()
+///     .method_handler("state_getStorage", |params| {
+///         // Only respond to a single key:
+///         let key: String = params.get(0)?;
+///         Ok((key == "0x1234").then_some("0x2a000000"))
+///     })
+///     .subscription_handler("chain_subscribeNewHeads", |_params| {
+///         Ok(vec![1, 2, 3])
+///     })
+///     .build();
+///
+/// let rpc_client = RpcClient::new(mock_client);
+///
+/// let value: Option<String> = rpc_client
+///     .request("state_getStorage", rpc_params!["0x1234"])
+///     .await
+///     .unwrap();
+/// assert_eq!(value.as_deref(), Some("0x2a000000"));
+///
+/// let items: Vec<u32> = rpc_client
+///     .subscribe("chain_subscribeNewHeads", rpc_params![], "chain_unsubscribeNewHeads")
+///     .await
+///     .unwrap()
+///     .map(|item| item.unwrap())
+///     .collect()
+///     .await;
+/// assert_eq!(items, vec![1, 2, 3]);
+/// # }
+/// ```
+pub struct MockRpcClient {
+    method_handlers: HashMap<String, MethodHandler>,
+    subscription_handlers: HashMap<String, SubscriptionHandler>,
+}
+
+impl MockRpcClient {
+    /// Construct a [`MockRpcClient`] by providing the handlers it should use.
+    pub fn builder() -> MockRpcClientBuilder {
+        MockRpcClientBuilder {
+            method_handlers: HashMap::new(),
+            subscription_handlers: HashMap::new(),
+            storage: HashMap::new(),
+            events: Vec::new(),
+        }
+    }
+}
+
+/// A builder for a [`MockRpcClient`].
+pub struct MockRpcClientBuilder {
+    method_handlers: HashMap<String, MethodHandler>,
+    subscription_handlers: HashMap<String, SubscriptionHandler>,
+    storage: HashMap<Vec<u8>, Vec<u8>>,
+    events: Vec<Vec<u8>>,
+}
+
+impl MockRpcClientBuilder {
+    /// Respond to requests for the given method using the given handler. The value returned
+    /// from the handler is serialized to JSON and returned as the result. This replaces any
+    /// handler previously given for the method.
+    pub fn method_handler<F, R>(mut self, method: impl Into<String>, handler: F) -> Self
+    where
+        F: Fn(MockParams) -> Result<R, RpcError> + Send + Sync + 'static,
+        R: Serialize,
+    {
+        let handler = move |params| to_raw_value(&handler(params)?);
+        self.method_handlers
+            .insert(method.into(), Box::new(handler));
+        self
+    }
+
+    /// Respond to subscriptions to the given method using the given handler. Each of the
+    /// values returned from the handler is serialized to JSON and handed back as an item in
+    /// the subscription, after which the subscription ends. This replaces any handler
+    /// previously given for the method.
+    pub fn subscription_handler<F, I>(mut self, method: impl Into<String>, handler: F) -> Self
+    where
+        F: Fn(MockParams) -> Result<I, RpcError> + Send + Sync + 'static,
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        let handler = move |params| {
+            handler(params)?
+                .into_iter()
+                .map(|item| to_raw_value(&item))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        self.subscription_handlers
+            .insert(method.into(), Box::new(handler));
+        self
+    }
+
+    /// Store the given value at some storage address, so that it's handed back when the
+    /// address is fetched via `state_getStorage` (at any block). The value is encoded to the
+    /// type of the storage entry in the metadata given.
+    ///
+    /// Providing a [`Self::method_handler()`] for `state_getStorage` replaces this.
+    pub fn with_storage<Addr, V>(
+        mut self,
+        metadata: &Metadata,
+        address: &Addr,
+        value: &V,
+    ) -> Result<Self, Error>
+    where
+        Addr: Address,
+        V: EncodeAsType + ?Sized,
+    {
+        let key = subxt_core::storage::get_address_bytes(address, metadata)?;
+        let (_, entry) = subxt_core::storage::lookup_storage_entry_details(
+            address.pallet_name(),
+            address.entry_name(),
+            metadata,
+        )?;
+        let value = value.encode_as_type(entry.entry_type().value_ty(), metadata.types())?;
+        self.storage.insert(key, value);
+        Ok(self)
+    }
+
+    /// Add events to those emitted in the `System.Events` storage entry, which is where
+    /// events are fetched from (see [`Self::with_storage()`]). Events are handed back in the
+    /// order that they are added, and each one is encoded using the metadata given.
+    pub fn with_events<E>(
+        mut self,
+        metadata: &Metadata,
+        events: impl IntoIterator<Item = (Phase, E)>,
+    ) -> Result<Self, Error>
+    where
+        E: StaticEvent + EncodeAsFields,
+    {
+        let pallet = metadata.pallet_by_name_err(E::PALLET)?;
+        let variant = pallet
+            .event_variants()
+            .and_then(|variants| variants.iter().find(|v| v.name == E::EVENT))
+            .ok_or_else(|| {
+                Error::Other(format!(
+                    "Event {}.{} not found in metadata",
+                    E::PALLET,
+                    E::EVENT
+                ))
+            })?;
+
+        for (phase, event) in events {
+            let mut record = phase.encode();
+            pallet.index().encode_to(&mut record);
+            variant.index.encode_to(&mut record);
+            let mut fields = variant
+                .fields
+                .iter()
+                .map(|f| scale_encode::Field::new(f.ty.id, f.name.as_deref()));
+            event.encode_as_fields_to(&mut fields, metadata.types(), &mut record)?;
+            // No topics:
+            Compact(0u32).encode_to(&mut record);
+            self.events.push(record);
+        }
+
+        let mut value = Compact(self.events.len() as u32).encode();
+        for record in &self.events {
+            value.extend_from_slice(record);
+        }
+        let address = crate::dynamic::storage("System", "Events", ());
+        let key = subxt_core::storage::get_address_bytes(&address, metadata)?;
+        self.storage.insert(key, value);
+        Ok(self)
+    }
+
+    /// Respond to any transaction submitted via `author_submitAndWatchExtrinsic` with the
+    /// given statuses, in order. For instance, a successful transaction might see
+    /// [`TransactionStatus::Ready`] followed by [`TransactionStatus::InBlock`] and then
+    /// [`TransactionStatus::Finalized`]. Events for the block can be given with
+    /// [`Self::with_events()`].
+    pub fn with_tx_outcome<Hash>(
+        self,
+        statuses: impl IntoIterator<Item = TransactionStatus<Hash>>,
+    ) -> Self
+    where
+        Hash: Serialize + Clone + Send + Sync + 'static,
+    {
+        let statuses: Vec<_> = statuses.into_iter().collect();
+        self.subscription_handler("author_submitAndWatchExtrinsic", move |_params| {
+            Ok(statuses.clone())
+        })
+    }
+
+    /// Build the [`MockRpcClient`].
+    pub fn build(mut self) -> MockRpcClient {
+        if !self.storage.is_empty() && !self.method_handlers.contains_key("state_getStorage") {
+            let storage = std::mem::take(&mut self.storage);
+            self = self.method_handler("state_getStorage", move |params| {
+                let key: Bytes = params.get(0)?;
+                Ok(storage.get(&key.0).cloned().map(Bytes))
+            });
+        }
+
+        MockRpcClient {
+            method_handlers: self.method_handlers,
+            subscription_handlers: self.subscription_handlers,
+        }
+    }
+}
+
+impl RpcClientT for MockRpcClient {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RawRpcFuture<'a, Box<RawValue>> {
+        Box::pin(async move {
+            let handler = self.method_handlers.get(method).ok_or_else(|| {
+                RpcError::request_rejected(format!("No handler for method '{method}'"))
+            })?;
+            handler(MockParams::new(params)?)
+        })
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        _unsub: &'a str,
+    ) -> RawRpcFuture<'a, RawRpcSubscription> {
+        Box::pin(async move {
+            let handler = self.subscription_handlers.get(sub).ok_or_else(|| {
+                RpcError::request_rejected(format!("No handler for subscription '{sub}'"))
+            })?;
+            let items = handler(MockParams::new(params)?)?;
+            Ok(RawRpcSubscription {
+                stream: futures::stream::iter(items.into_iter().map(Ok)).boxed(),
+                id: None,
+            })
+        })
+    }
+}
+
+/// The parameters that a [`MockRpcClient`] handler is called with.
+#[derive(Debug, Clone)]
+pub struct MockParams(Vec<serde_json::Value>);
+
+impl MockParams {
+    fn new(params: Option<Box<RawValue>>) -> Result<Self, RpcError> {
+        let params = match params {
+            Some(params) => serde_json::from_str(params.get())
+                .map_err(|e| RpcError::request_rejected(format!("Invalid params: {e}")))?,
+            None => Vec::new(),
+        };
+        Ok(MockParams(params))
+    }
+
+    /// The number of parameters given.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Were no parameters given?
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Deserialize the parameter at the given index into some type. Parameters which weren't
+    /// provided are treated as `null`, so that optional parameters can be deserialized into
+    /// an `Option`. Returns an error if the parameter can't be deserialized into the type.
+    pub fn get<T: DeserializeOwned>(&self, index: usize) -> Result<T, RpcError> {
+        let param = self.0.get(index).cloned().unwrap_or_default();
+        serde_json::from_value(param)
+            .map_err(|e| RpcError::request_rejected(format!("Invalid param {index}: {e}")))
+    }
+}
+
+fn to_raw_value<T: Serialize>(value: &T) -> Result<Box<RawValue>, RpcError> {
+    serde_json::value::to_raw_value(value).map_err(|e| RpcError::ClientError(Box::new(e)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{backend::rpc::rpc_params, backend::rpc::RpcClient, Metadata, OnlineClient};
+    use crate::{client::RuntimeVersion, utils::H256, SubstrateConfig};
+    use codec::{Decode, Encode};
+
+    #[tokio::test]
+    async fn handlers_are_called_with_params() {
+        let client = RpcClient::new(
+            MockRpcClient::builder()
+                .method_handler("add", |params| {
+                    Ok(params.get::<u32>(0)? + params.get::<u32>(1)?)
+                })
+                .method_handler("optional", |params| params.get::<Option<u32>>(0))
+                .build(),
+        );
+
+        let res: u32 = client.request("add", rpc_params![1, 2]).await.unwrap();
+        assert_eq!(res, 3);
+
+        let res: Option<u32> = client.request("optional", rpc_params![]).await.unwrap();
+        assert_eq!(res, None);
+
+        // Bad params and unknown methods lead to errors:
+        let res = client.request::<u32>("add", rpc_params![1]).await;
+        assert!(res.is_err());
+        let res = client.request::<u32>("unknown", rpc_params![]).await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn online_client_can_use_mock() {
+        let metadata_bytes = include_bytes!("../../../../artifacts/polkadot_metadata_small.scale");
+        let metadata = Metadata::decode(&mut &metadata_bytes[..]).unwrap();
+
+        let mock_client = MockRpcClient::builder()
+            .method_handler("state_getStorage", |_params| {
+                Ok(format!("0x{}", hex::encode(1234u32.encode())))
+            })
+            .build();
+
+        let api = OnlineClient::<SubstrateConfig>::from_rpc_client_with(
+            H256::zero(),
+            RuntimeVersion {
+                spec_version: 1,
+                transaction_version: 1,
+            },
+            metadata,
+            mock_client,
+        )
+        .unwrap();
+
+        let address = crate::dynamic::storage("System", "Number", ());
+        let number = api
+            .storage()
+            .at(H256::zero())
+            .fetch(&address)
+            .await
+            .unwrap()
+            .unwrap()
+            .to_value()
+            .unwrap();
+        assert_eq!(number.as_u128(), Some(1234));
+    }
+
+    #[tokio::test]
+    async fn typed_helpers_provide_storage_events_and_tx_outcome() {
+        use crate::{dynamic::Value, events::StaticEvent, utils::AccountId32};
+
+        #[derive(Debug, PartialEq, scale_encode::EncodeAsType, scale_decode::DecodeAsType)]
+        struct Remarked {
+            sender: AccountId32,
+            hash: H256,
+        }
+        impl StaticEvent for Remarked {
+            const PALLET: &'static str = "System";
+            const EVENT: &'static str = "Remarked";
+        }
+
+        let metadata_bytes = include_bytes!("../../../../artifacts/polkadot_metadata_small.scale");
+        let metadata = Metadata::decode(&mut &metadata_bytes[..]).unwrap();
+
+        let remarked = Remarked {
+            sender: AccountId32([1; 32]),
+            hash: H256::repeat_byte(2),
+        };
+        let block_hash = H256::repeat_byte(3);
+        let mock_client = MockRpcClient::builder()
+            .with_storage(
+                &metadata,
+                &crate::dynamic::storage("System", "Number", ()),
+                &1234u32,
+            )
+            .unwrap()
+            .with_events(&metadata, [(Phase::ApplyExtrinsic(1), remarked)])
+            .unwrap()
+            .with_tx_outcome([
+                TransactionStatus::Ready,
+                TransactionStatus::InBlock(block_hash),
+            ])
+            .build();
+
+        let api = OnlineClient::<SubstrateConfig>::from_rpc_client_with(
+            H256::zero(),
+            RuntimeVersion {
+                spec_version: 1,
+                transaction_version: 1,
+            },
+            metadata,
+            mock_client,
+        )
+        .unwrap();
+
+        // Storage:
+        let number = api
+            .storage()
+            .at(H256::zero())
+            .fetch(&crate::dynamic::storage("System", "Number", ()))
+            .await
+            .unwrap()
+            .unwrap()
+            .to_value()
+            .unwrap();
+        assert_eq!(number.as_u128(), Some(1234));
+
+        // Events:
+        let events = api.events().at(block_hash).await.unwrap();
+        let event = events.find_first::<Remarked>().unwrap().unwrap();
+        assert_eq!(event.sender, AccountId32([1; 32]));
+        assert_eq!(event.hash, H256::repeat_byte(2));
+
+        // Transaction outcome:
+        let tx = crate::dynamic::tx("System", "remark", vec![Value::from_bytes([1, 2, 3])]);
+        let tx = api.tx().create_unsigned(&tx).unwrap();
+        let mut progress = tx.submit_and_watch().await.unwrap();
+        let mut statuses = Vec::new();
+        while let Some(status) = progress.next().await {
+            statuses.push(status.unwrap());
+        }
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(
+            statuses[1].as_in_block().map(|b| b.block_hash()),
+            Some(block_hash)
+        );
+    }
+}
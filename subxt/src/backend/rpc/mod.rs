@@ -14,6 +14,14 @@
 //!   methods.
 //! - [`RpcClient`] is the higher level wrapper around this, offering
 //!   the [`RpcClient::request`] and [`RpcClient::subscribe`] methods.
+//! - [`FailoverRpcClient`] is an [`RpcClientT`] which fails over between several
+//!   other clients, so that Subxt isn't reliant on a single node.
+//! - [`TimeoutRpcClient`] is an [`RpcClientT`] which gives up on requests and
+//!   subscriptions if the node takes too long to respond.
+//! - [`RetryRpcClient`] is an [`RpcClientT`] which retries requests that fail
+//!   with transient errors.
+//! - [`MockRpcClient`] is an [`RpcClientT`] which responds to requests using handlers
+//!   that it's given, for testing code which uses Subxt without a running node.
 //!
 //! # Example
 //!
@@ -51,6 +59,60 @@
 //! let client = OnlineClient::<SubstrateConfig>::from_rpc_client(rpc_client);
 //! # }
 //! ```
+//!
+//! # Custom RPC methods
+//!
+//! [`RpcClient::request`] and [`RpcClient::subscribe`] accept any method name, and so can be
+//! used to call RPC methods that are specific to some chain. To provide typed access to them,
+//! wrap an [`RpcClient`] in your own type in the same way that
+//! [`crate::backend::legacy::LegacyRpcMethods`] does:
+//!
+//! ```no_run
+//! # #[tokio::main]
+//! # async fn main() {
+//! use subxt::backend::rpc::{rpc_params, RpcClient, RpcSubscription};
+//! use subxt::{Error, OnlineClient, PolkadotConfig};
+//!
+//! /// The custom RPC methods that our chain exposes.
+//! #[derive(Clone)]
+//! struct MyChainRpcMethods {
+//!     client: RpcClient,
+//! }
+//!
+//! impl MyChainRpcMethods {
+//!     fn new(client: RpcClient) -> Self {
+//!         MyChainRpcMethods { client }
+//!     }
+//!
+//!     /// Call the custom `myChain_poolSize` method.
+//!     async fn pool_size(&self, pool_id: u32) -> Result<u64, Error> {
+//!         self.client
+//!             .request("myChain_poolSize", rpc_params![pool_id])
+//!             .await
+//!     }
+//!
+//!     /// Subscribe to the custom `myChain_subscribePrices` method.
+//!     async fn subscribe_prices(&self) -> Result<RpcSubscription<Vec<u128>>, Error> {
+//!         self.client
+//!             .subscribe(
+//!                 "myChain_subscribePrices",
+//!                 rpc_params![],
+//!                 "myChain_unsubscribePrices",
+//!             )
+//!             .await
+//!     }
+//! }
+//!
+//! let rpc_client = RpcClient::from_url("ws://localhost:9944").await.unwrap();
+//!
+//! // The same connection can be shared between our custom methods and Subxt:
+//! let my_chain_rpc = MyChainRpcMethods::new(rpc_client.clone());
+//! let api = OnlineClient::<PolkadotConfig>::from_rpc_client(rpc_client).await.unwrap();
+//!
+//! let pool_size = my_chain_rpc.pool_size(1).await.unwrap();
+//! let mut prices = my_chain_rpc.subscribe_prices().await.unwrap();
+//! # }
+//! ```
 
 // Allow an `rpc.rs` file in the `rpc` folder to align better
 // with other file names for their types.
@@ -69,8 +131,16 @@ crate::macros::cfg_reconnecting_rpc_client! {
     pub use reconnecting_jsonrpsee_ws_client as reconnecting_rpc_client;
 }
 
+mod failover_rpc_client;
+mod mock_rpc_client;
+mod retry_rpc_client;
 mod rpc_client;
 mod rpc_client_t;
+mod timeout_rpc_client;
 
+pub use failover_rpc_client::FailoverRpcClient;
+pub use mock_rpc_client::{MockParams, MockRpcClient, MockRpcClientBuilder};
+pub use retry_rpc_client::RetryRpcClient;
 pub use rpc_client::{rpc_params, RpcClient, RpcParams, RpcSubscription};
 pub use rpc_client_t::{RawRpcFuture, RawRpcSubscription, RawValue, RpcClientT};
+pub use timeout_rpc_client::TimeoutRpcClient;
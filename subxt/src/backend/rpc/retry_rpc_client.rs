@@ -0,0 +1,282 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use super::{RawRpcFuture, RawRpcSubscription, RpcClient, RpcClientT};
+use crate::error::RpcError;
+use futures_timer::Delay;
+use serde_json::value::RawValue;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// An RPC client which wraps another, and retries requests which fail with a transient
+/// error (see [`RpcError::is_transient()`]), waiting for an exponentially increasing delay
+/// between each attempt.
+///
+/// Only requests which are safe to repeat are retried. Methods which submit transactions or
+/// modify the node's keys (those in the `author_`, `transaction_` and `transactionWatch_`
+/// namespaces) are never retried, since the first attempt may have taken effect even though
+/// we didn't hear back about it. Subscriptions are retried in the same way when they are
+/// being established, but not once they have started.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() {
+/// use std::time::Duration;
+/// use subxt::backend::rpc::{RetryRpcClient, RpcClient};
+/// use subxt::{OnlineClient, PolkadotConfig};
+///
+/// let rpc_client = RpcClient::from_url("wss://rpc.example.com:443").await.unwrap();
+/// let rpc_client = RetryRpcClient::new(rpc_client)
+///     .with_max_attempts(5)
+///     .with_backoff(Duration::from_millis(200), Duration::from_secs(5));
+///
+/// let api = OnlineClient::<PolkadotConfig>::from_rpc_client(RpcClient::new(rpc_client))
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+pub struct RetryRpcClient {
+    client: RpcClient,
+    max_attempts: usize,
+    initial_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+}
+
+impl RetryRpcClient {
+    /// Wrap the given client. By default, requests are attempted up to 3 times, starting
+    /// with a delay of 100ms between attempts and doubling it each time up to 10 seconds,
+    /// with some random jitter applied to each delay.
+    pub fn new(client: impl Into<RpcClient>) -> Self {
+        RetryRpcClient {
+            client: client.into(),
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+        }
+    }
+
+    /// The maximum number of times to attempt a request, including the first attempt.
+    /// A value of 0 is treated as 1.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// The delay to wait before the first retry, which doubles for each retry thereafter
+    /// up to the maximum delay given.
+    pub fn with_backoff(mut self, initial_delay: Duration, max_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Whether to randomly shorten each delay by up to half. This avoids many clients
+    /// which failed at the same time from retrying at the same time too. Defaults to `true`.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// The delay to wait before the given retry (where the first retry is 1).
+    fn delay(&self, retry: usize) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1) as u32);
+        let delay = self
+            .initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay);
+        if !self.jitter {
+            return delay;
+        }
+
+        // This doesn't need to be cryptographically random, so rather than pull in
+        // a dependency we use the randomly seeded hasher from the standard library.
+        let random = std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish();
+        delay.mul_f64(0.5 + (random as f64 / u64::MAX as f64) / 2.0)
+    }
+
+    /// Run the given function, retrying it if it fails with a transient error.
+    async fn with_retries<'a, R>(
+        &'a self,
+        f: impl Fn(&'a RpcClient) -> RawRpcFuture<'a, R> + Send,
+    ) -> Result<R, RpcError> {
+        let mut attempt = 1;
+        loop {
+            match f(&self.client).await {
+                Err(e) if e.is_transient() && attempt < self.max_attempts => {
+                    let delay = self.delay(attempt);
+                    tracing::debug!("RPC request failed, retrying in {delay:?}: {e}");
+                    Delay::new(delay).await;
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+}
+
+impl RpcClientT for RetryRpcClient {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RawRpcFuture<'a, Box<RawValue>> {
+        if !is_safe_to_retry(method) {
+            return self.client.request_raw(method, params);
+        }
+        Box::pin(self.with_retries(move |client| client.request_raw(method, params.clone())))
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RawRpcFuture<'a, RawRpcSubscription> {
+        if !is_safe_to_retry(sub) {
+            return self.client.subscribe_raw(sub, params, unsub);
+        }
+        Box::pin(self.with_retries(move |client| client.subscribe_raw(sub, params.clone(), unsub)))
+    }
+
+    fn batch_request_raw<'a>(
+        &'a self,
+        requests: Vec<(&'a str, Option<Box<RawValue>>)>,
+    ) -> RawRpcFuture<'a, Vec<Result<Box<RawValue>, RpcError>>> {
+        if !requests.iter().all(|(method, _)| is_safe_to_retry(method)) {
+            return self.client.batch_request_raw(requests);
+        }
+        Box::pin(self.with_retries(move |client| client.batch_request_raw(requests.clone())))
+    }
+}
+
+/// Can the given method be called again without any ill effects if we don't know whether
+/// a previous call to it succeeded?
+pub(super) fn is_safe_to_retry(method: &str) -> bool {
+    const UNSAFE_PREFIXES: [&str; 3] = ["author_", "transaction_", "transactionWatch_"];
+    !UNSAFE_PREFIXES
+        .iter()
+        .any(|prefix| method.starts_with(prefix))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// A client which fails the given number of requests with the given error, and then
+    /// succeeds. The number of requests made to it is counted.
+    struct FlakyClient {
+        failures: usize,
+        error: fn() -> RpcError,
+        requests: Arc<AtomicUsize>,
+    }
+
+    impl FlakyClient {
+        /// Wrap a new [`FlakyClient`] in a [`RetryRpcClient`] with short delays.
+        fn retrying(
+            failures: usize,
+            error: fn() -> RpcError,
+        ) -> (RetryRpcClient, Arc<AtomicUsize>) {
+            let requests = Arc::new(AtomicUsize::new(0));
+            let client = FlakyClient {
+                failures,
+                error,
+                requests: requests.clone(),
+            };
+            let client = RetryRpcClient::new(client)
+                .with_backoff(Duration::from_millis(1), Duration::from_millis(5));
+            (client, requests)
+        }
+    }
+
+    impl RpcClientT for FlakyClient {
+        fn request_raw<'a>(
+            &'a self,
+            _method: &'a str,
+            _params: Option<Box<RawValue>>,
+        ) -> RawRpcFuture<'a, Box<RawValue>> {
+            let n = self.requests.fetch_add(1, Ordering::Relaxed);
+            let res = if n < self.failures {
+                Err((self.error)())
+            } else {
+                Ok(RawValue::from_string("1".to_string()).unwrap())
+            };
+            Box::pin(std::future::ready(res))
+        }
+
+        fn subscribe_raw<'a>(
+            &'a self,
+            _sub: &'a str,
+            _params: Option<Box<RawValue>>,
+            _unsub: &'a str,
+        ) -> RawRpcFuture<'a, RawRpcSubscription> {
+            Box::pin(std::future::ready(Err(RpcError::SubscriptionsNotSupported)))
+        }
+    }
+
+    #[tokio::test]
+    async fn transient_errors_are_retried() {
+        let (client, requests) = FlakyClient::retrying(2, || RpcError::Timeout);
+        assert!(client.request_raw("state_getStorage", None).await.is_ok());
+        assert_eq!(requests.load(Ordering::Relaxed), 3);
+
+        // We give up after the maximum number of attempts:
+        let (client, requests) = FlakyClient::retrying(5, || RpcError::Timeout);
+        let client = client.with_max_attempts(4);
+        assert!(matches!(
+            client.request_raw("state_getStorage", None).await,
+            Err(RpcError::Timeout)
+        ));
+        assert_eq!(requests.load(Ordering::Relaxed), 4);
+    }
+
+    #[tokio::test]
+    async fn only_safe_requests_and_transient_errors_are_retried() {
+        let (client, requests) = FlakyClient::retrying(1, || RpcError::request_rejected("nope"));
+        assert!(client.request_raw("state_getStorage", None).await.is_err());
+        assert_eq!(requests.load(Ordering::Relaxed), 1);
+
+        let (client, requests) = FlakyClient::retrying(1, || RpcError::Timeout);
+        assert!(client
+            .request_raw("author_submitExtrinsic", None)
+            .await
+            .is_err());
+        assert_eq!(requests.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn delays_back_off_exponentially() {
+        let millis = Duration::from_millis;
+        let (client, _) = FlakyClient::retrying(0, || RpcError::Timeout);
+        let client = client
+            .with_backoff(millis(100), millis(1000))
+            .with_jitter(false);
+
+        let delays: Vec<_> = (1..=6).map(|n| client.delay(n)).collect();
+        assert_eq!(
+            delays,
+            vec![
+                millis(100),
+                millis(200),
+                millis(400),
+                millis(800),
+                millis(1000),
+                millis(1000)
+            ]
+        );
+
+        let client = client.with_jitter(true);
+        for n in 1..=6 {
+            let delay = client.delay(n);
+            assert!(delay >= delays[n - 1] / 2 && delay <= delays[n - 1]);
+        }
+    }
+}
@@ -32,7 +32,23 @@ impl RpcClient {
     /// Create a default RPC client pointed at some URL, currently based on [`jsonrpsee`].
     ///
     /// Allows insecure URLs without SSL encryption, e.g. (http:// and ws:// URLs).
+    ///
+    /// On native targets, `http://` and `https://` URLs are also accepted. In this case,
+    /// requests are sent over HTTP, and any attempt to subscribe will fail with
+    /// [`crate::error::RpcError::SubscriptionsNotSupported`]. This means that things like
+    /// subscribing to blocks or watching the progress of a transaction won't work,
+    /// but fetching storage and calling runtime APIs will. Transactions can be submitted
+    /// with the legacy backend, which falls back to `author_submitExtrinsic` when it can't
+    /// subscribe to `author_submitAndWatchExtrinsic`; in this case the transaction progress
+    /// reports only that the transaction was validated, and then ends.
     pub async fn from_insecure_url<U: AsRef<str>>(url: U) -> Result<Self, Error> {
+        #[cfg(feature = "native")]
+        if jsonrpsee_helpers::is_http_url(url.as_ref()) {
+            let client = jsonrpsee_helpers::http_client(url.as_ref())
+                .map_err(|e| crate::error::RpcError::ClientError(Box::new(e)))?;
+            return Ok(Self::new(client));
+        }
+
         let client = jsonrpsee_helpers::client(url.as_ref())
             .await
             .map_err(|e| crate::error::RpcError::ClientError(Box::new(e)))?;
@@ -77,6 +93,52 @@ impl RpcClient {
             .await?;
         Ok(RpcSubscription::new(sub))
     }
+
+    /// Make a batch of RPC requests, each given as a method name and some parameters, which
+    /// all return the same type. Where the underlying client supports it, these are sent to
+    /// the node as a single JSON-RPC batch, saving a round trip per request.
+    ///
+    /// The results are returned in the same order as the requests. The outer result fails if
+    /// the batch could not be sent at all, and each inner result fails if that request failed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use subxt::backend::rpc::{rpc_params, RpcClient};
+    ///
+    /// let rpc_client = RpcClient::from_url("ws://localhost:9944").await.unwrap();
+    ///
+    /// // Fetch the hashes of the first 10 blocks in one go:
+    /// let hashes: Vec<Result<Option<String>, _>> = rpc_client
+    ///     .batch_request((0..10u32).map(|n| ("chain_getBlockHash", rpc_params![n])))
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn batch_request<'a, Res: DeserializeOwned>(
+        &self,
+        requests: impl IntoIterator<Item = (&'a str, RpcParams)>,
+    ) -> Result<Vec<Result<Res, Error>>, Error> {
+        let requests: Vec<_> = requests
+            .into_iter()
+            .map(|(method, params)| (method, params.build()))
+            .collect();
+        if requests.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let res = self.client.batch_request_raw(requests).await?;
+        let vals = res
+            .into_iter()
+            .map(|r| {
+                let raw = r?;
+                Ok(serde_json::from_str(raw.get())?)
+            })
+            .collect();
+        Ok(vals)
+    }
 }
 
 impl<C: RpcClientT> From<C> for RpcClient {
@@ -249,6 +311,7 @@ mod jsonrpsee_helpers {
     pub use jsonrpsee::{
         client_transport::ws::{self, EitherStream, Url, WsTransportClientBuilder},
         core::client::{Client, Error},
+        http_client::{HttpClient, HttpClientBuilder},
     };
     use tokio_util::compat::Compat;
 
@@ -270,6 +333,18 @@ mod jsonrpsee_helpers {
             .await
             .map_err(|e| Error::Transport(e.into()))
     }
+
+    /// Does the URL point at an HTTP(S), rather than a WS(S), endpoint?
+    pub fn is_http_url(url: &str) -> bool {
+        Url::parse(url)
+            .map(|url| matches!(url.scheme(), "http" | "https"))
+            .unwrap_or(false)
+    }
+
+    /// Build HTTP RPC client from URL
+    pub fn http_client(url: &str) -> Result<HttpClient, Error> {
+        HttpClientBuilder::default().build(url)
+    }
 }
 
 // helpers for a jsonrpsee specific RPC client.
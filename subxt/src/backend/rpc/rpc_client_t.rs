@@ -51,6 +51,27 @@ pub trait RpcClientT: Send + Sync + 'static {
         params: Option<Box<RawValue>>,
         unsub: &'a str,
     ) -> RawRpcFuture<'a, RawRpcSubscription>;
+
+    /// Make a batch of raw requests, returning the result of each one in the order that they
+    /// were given. The outer result fails if the batch as a whole could not be sent, and each
+    /// inner result fails if the corresponding request failed.
+    ///
+    /// By default, each request is made separately via [`RpcClientT::request_raw`] and the
+    /// results are awaited concurrently. Implementations which are able to send these as a
+    /// single JSON-RPC batch should override this.
+    ///
+    /// Prefer to use the interface provided on [`super::RpcClient`] where possible.
+    fn batch_request_raw<'a>(
+        &'a self,
+        requests: Vec<(&'a str, Option<Box<RawValue>>)>,
+    ) -> RawRpcFuture<'a, Vec<Result<Box<RawValue>, RpcError>>> {
+        Box::pin(async move {
+            let requests = requests
+                .into_iter()
+                .map(|(method, params)| self.request_raw(method, params));
+            Ok(futures::future::join_all(requests).await)
+        })
+    }
 }
 
 /// A boxed future that is returned from the [`RpcClientT`] methods.
@@ -81,6 +102,13 @@ impl<T: RpcClientT> RpcClientT for std::sync::Arc<T> {
     ) -> RawRpcFuture<'a, RawRpcSubscription> {
         (**self).subscribe_raw(sub, params, unsub)
     }
+
+    fn batch_request_raw<'a>(
+        &'a self,
+        requests: Vec<(&'a str, Option<Box<RawValue>>)>,
+    ) -> RawRpcFuture<'a, Vec<Result<Box<RawValue>, RpcError>>> {
+        (**self).batch_request_raw(requests)
+    }
 }
 
 impl<T: RpcClientT> RpcClientT for Box<T> {
@@ -100,4 +128,11 @@ impl<T: RpcClientT> RpcClientT for Box<T> {
     ) -> RawRpcFuture<'a, RawRpcSubscription> {
         (**self).subscribe_raw(sub, params, unsub)
     }
+
+    fn batch_request_raw<'a>(
+        &'a self,
+        requests: Vec<(&'a str, Option<Box<RawValue>>)>,
+    ) -> RawRpcFuture<'a, Vec<Result<Box<RawValue>, RpcError>>> {
+        (**self).batch_request_raw(requests)
+    }
 }
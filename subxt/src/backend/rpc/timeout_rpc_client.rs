@@ -0,0 +1,221 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use super::{RawRpcFuture, RawRpcSubscription, RpcClient, RpcClientT};
+use crate::error::RpcError;
+use futures::future::{select, Either};
+use futures::StreamExt;
+use futures_timer::Delay;
+use serde_json::value::RawValue;
+use std::time::Duration;
+
+/// An RPC client which wraps another, and returns [`RpcError::Timeout`] if the node takes
+/// too long to respond, rather than waiting forever. No timeouts are applied until they are
+/// configured.
+///
+/// The timeout for establishing a connection is configured on the underlying client; the
+/// default jsonrpsee based client gives up on connecting after 10 seconds.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() {
+/// use std::time::Duration;
+/// use subxt::backend::rpc::{RpcClient, TimeoutRpcClient};
+/// use subxt::{OnlineClient, PolkadotConfig};
+///
+/// let rpc_client = RpcClient::from_url("wss://rpc.example.com:443").await.unwrap();
+/// let rpc_client = TimeoutRpcClient::new(rpc_client)
+///     .with_request_timeout(Duration::from_secs(30))
+///     .with_subscription_idle_timeout(Duration::from_secs(60));
+///
+/// let api = OnlineClient::<PolkadotConfig>::from_rpc_client(RpcClient::new(rpc_client))
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+pub struct TimeoutRpcClient {
+    client: RpcClient,
+    request_timeout: Option<Duration>,
+    subscription_idle_timeout: Option<Duration>,
+}
+
+impl TimeoutRpcClient {
+    /// Wrap the given client. No timeouts are applied until they are configured.
+    pub fn new(client: impl Into<RpcClient>) -> Self {
+        TimeoutRpcClient {
+            client: client.into(),
+            request_timeout: None,
+            subscription_idle_timeout: None,
+        }
+    }
+
+    /// Set how long to wait for the response to a request (including a batch of requests,
+    /// or a request to start a subscription) before giving up on it.
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Set how long to wait for each item on a subscription. If no item arrives in this
+    /// time, the subscription returns [`RpcError::Timeout`] and then ends.
+    ///
+    /// Some subscriptions are expected to be quiet for a while (for instance, those watching
+    /// for changes to a storage entry), so this should be set with that in mind.
+    pub fn with_subscription_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.subscription_idle_timeout = Some(timeout);
+        self
+    }
+}
+
+impl RpcClientT for TimeoutRpcClient {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RawRpcFuture<'a, Box<RawValue>> {
+        with_timeout(
+            self.request_timeout,
+            self.client.request_raw(method, params),
+        )
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RawRpcFuture<'a, RawRpcSubscription> {
+        let fut = with_timeout(
+            self.request_timeout,
+            self.client.subscribe_raw(sub, params, unsub),
+        );
+        let Some(idle_timeout) = self.subscription_idle_timeout else {
+            return fut;
+        };
+
+        Box::pin(async move {
+            let RawRpcSubscription { stream, id } = fut.await?;
+            let stream = futures::stream::unfold(Some(stream), move |stream| async move {
+                let mut stream = stream?;
+                let next = match select(stream.next(), Delay::new(idle_timeout)).await {
+                    Either::Left((next, _)) => next,
+                    // Dropping the stream here will unsubscribe from it.
+                    Either::Right(_) => return Some((Err(RpcError::Timeout), None)),
+                };
+                next.map(|item| (item, Some(stream)))
+            });
+            Ok(RawRpcSubscription {
+                stream: stream.boxed(),
+                id,
+            })
+        })
+    }
+
+    fn batch_request_raw<'a>(
+        &'a self,
+        requests: Vec<(&'a str, Option<Box<RawValue>>)>,
+    ) -> RawRpcFuture<'a, Vec<Result<Box<RawValue>, RpcError>>> {
+        with_timeout(
+            self.request_timeout,
+            self.client.batch_request_raw(requests),
+        )
+    }
+}
+
+/// Return an error if the given future doesn't complete within the timeout, if one is given.
+fn with_timeout<'a, R: Send + 'a>(
+    timeout: Option<Duration>,
+    fut: RawRpcFuture<'a, R>,
+) -> RawRpcFuture<'a, R> {
+    let Some(timeout) = timeout else {
+        return fut;
+    };
+    Box::pin(async move {
+        match select(fut, Delay::new(timeout)).await {
+            Either::Left((res, _)) => res,
+            Either::Right(_) => Err(RpcError::Timeout),
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A client which answers requests after the given delay, and whose subscriptions
+    /// produce a single item after the given delay and then nothing more.
+    struct SlowClient(Duration);
+
+    impl RpcClientT for SlowClient {
+        fn request_raw<'a>(
+            &'a self,
+            _method: &'a str,
+            _params: Option<Box<RawValue>>,
+        ) -> RawRpcFuture<'a, Box<RawValue>> {
+            Box::pin(async move {
+                Delay::new(self.0).await;
+                Ok(RawValue::from_string("1".to_string()).unwrap())
+            })
+        }
+
+        fn subscribe_raw<'a>(
+            &'a self,
+            _sub: &'a str,
+            _params: Option<Box<RawValue>>,
+            _unsub: &'a str,
+        ) -> RawRpcFuture<'a, RawRpcSubscription> {
+            let delay = self.0;
+            let stream = futures::stream::once(async move {
+                Delay::new(delay).await;
+                Ok::<_, RpcError>(RawValue::from_string("1".to_string()).unwrap())
+            })
+            .chain(futures::stream::pending());
+            Box::pin(async move {
+                Ok(RawRpcSubscription {
+                    stream: stream.boxed(),
+                    id: None,
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn requests_time_out() {
+        let millis = Duration::from_millis;
+
+        let client =
+            TimeoutRpcClient::new(SlowClient(millis(10))).with_request_timeout(millis(1000));
+        assert!(client.request_raw("foo", None).await.is_ok());
+
+        let client =
+            TimeoutRpcClient::new(SlowClient(millis(1000))).with_request_timeout(millis(10));
+        assert!(matches!(
+            client.request_raw("foo", None).await,
+            Err(RpcError::Timeout)
+        ));
+        assert!(matches!(
+            client.batch_request_raw(vec![("foo", None)]).await,
+            Err(RpcError::Timeout)
+        ));
+    }
+
+    #[tokio::test]
+    async fn idle_subscriptions_time_out() {
+        let millis = Duration::from_millis;
+
+        let client = TimeoutRpcClient::new(SlowClient(millis(10)))
+            .with_subscription_idle_timeout(millis(200));
+        let mut sub = client.subscribe_raw("foo", None, "unfoo").await.unwrap();
+
+        // The first item arrives in time, and then we time out waiting for the next:
+        assert!(sub.stream.next().await.unwrap().is_ok());
+        assert!(matches!(
+            sub.stream.next().await,
+            Some(Err(RpcError::Timeout))
+        ));
+        assert!(sub.stream.next().await.is_none());
+    }
+}
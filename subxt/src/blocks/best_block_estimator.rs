@@ -0,0 +1,122 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::metadata::Metadata;
+use codec::Decode;
+use std::time::Duration;
+
+/// Estimates the current best block number in between new head notifications, based on
+/// the expected time between blocks. This allows a UI to show a smoothly advancing block
+/// height, or a mortal transaction to be given a birth block closer to the real best block
+/// on a slow connection.
+///
+/// To avoid depending on any particular clock (which differs between native and web targets),
+/// times are given as a [`Duration`] since any fixed point in time of the caller's choosing,
+/// for instance the time since the application started.
+#[derive(Clone, Debug)]
+pub struct BestBlockEstimator {
+    block_time: Duration,
+    latency: Duration,
+    last_head: Option<(u64, Duration)>,
+}
+
+impl BestBlockEstimator {
+    /// Create a new estimator, given the expected time between blocks.
+    pub fn new(block_time: Duration) -> Self {
+        BestBlockEstimator {
+            block_time,
+            latency: Duration::ZERO,
+            last_head: None,
+        }
+    }
+
+    /// Create a new estimator, using the expected time between blocks from the metadata.
+    /// See [`expected_block_time()`]. Returns `None` if this can't be found.
+    pub fn from_metadata(metadata: &Metadata) -> Option<Self> {
+        expected_block_time(metadata).map(Self::new)
+    }
+
+    /// Set how long new head notifications typically take to reach us after the block was
+    /// produced. This is added on to the time since the last notification when estimating.
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// The expected time between blocks that this estimator is using.
+    pub fn block_time(&self) -> Duration {
+        self.block_time
+    }
+
+    /// Record that a new best block with the given number was seen at the given time.
+    /// Blocks lower than the last one seen (for instance, after a re-org) are also recorded.
+    pub fn observe_head(&mut self, number: u64, now: Duration) {
+        self.last_head = Some((number, now));
+    }
+
+    /// Estimate the best block number at the given time, or `None` if no heads have been
+    /// observed yet.
+    pub fn estimate(&self, now: Duration) -> Option<u64> {
+        let (number, seen_at) = self.last_head?;
+        if self.block_time.is_zero() {
+            return Some(number);
+        }
+        let elapsed = now.saturating_sub(seen_at) + self.latency;
+        let blocks_since = elapsed.as_millis() / self.block_time.as_millis().max(1);
+        Some(number.saturating_add(blocks_since as u64))
+    }
+}
+
+/// Find the expected time between blocks from the metadata. This uses the `Babe::ExpectedBlockTime`
+/// constant if it exists, and otherwise assumes that a block is produced every other timestamp
+/// period, as is the case for chains using Aura, using the `Timestamp::MinimumPeriod` constant.
+pub fn expected_block_time(metadata: &Metadata) -> Option<Duration> {
+    let constant_u64 = |pallet: &str, constant: &str| {
+        let value = metadata
+            .pallet_by_name(pallet)?
+            .constant_by_name(constant)?
+            .value();
+        u64::decode(&mut &*value).ok()
+    };
+
+    if let Some(millis) = constant_u64("Babe", "ExpectedBlockTime") {
+        return Some(Duration::from_millis(millis));
+    }
+    constant_u64("Timestamp", "MinimumPeriod").map(|millis| Duration::from_millis(millis * 2))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn estimates_advance_with_time() {
+        let secs = Duration::from_secs;
+        let mut estimator = BestBlockEstimator::new(secs(6));
+        assert_eq!(estimator.estimate(secs(0)), None);
+
+        estimator.observe_head(100, secs(10));
+        assert_eq!(estimator.estimate(secs(10)), Some(100));
+        assert_eq!(estimator.estimate(secs(15)), Some(100));
+        assert_eq!(estimator.estimate(secs(16)), Some(101));
+        assert_eq!(estimator.estimate(secs(40)), Some(105));
+
+        // A new head resets the estimate:
+        estimator.observe_head(102, secs(20));
+        assert_eq!(estimator.estimate(secs(20)), Some(102));
+
+        // Times before the last head don't go backwards:
+        assert_eq!(estimator.estimate(secs(0)), Some(102));
+    }
+
+    #[test]
+    fn latency_is_compensated_for() {
+        let secs = Duration::from_secs;
+        let mut estimator = BestBlockEstimator::new(secs(6)).with_latency(secs(2));
+
+        estimator.observe_head(100, secs(10));
+        assert_eq!(estimator.estimate(secs(13)), Some(100));
+        assert_eq!(estimator.estimate(secs(14)), Some(101));
+    }
+}
@@ -16,7 +16,8 @@ use subxt_core::blocks::{ExtrinsicDetails as CoreExtrinsicDetails, Extrinsics as
 
 // Re-export anything that's directly returned/used in the APIs below.
 pub use subxt_core::blocks::{
-    ExtrinsicMetadataDetails, ExtrinsicSignedExtension, ExtrinsicSignedExtensions, StaticExtrinsic,
+    CallTree, ExtrinsicMetadataDetails, ExtrinsicSignedExtension, ExtrinsicSignedExtensions,
+    SignedPayload, StaticExtrinsic,
 };
 
 /// The body of a block.
@@ -126,6 +127,36 @@ where
     pub fn has<E: StaticExtrinsic>(&self) -> Result<bool, Error> {
         Ok(self.find::<E>().next().transpose()?.is_some())
     }
+
+    /// See [`subxt_core::blocks::Extrinsics::verify_signatures_batch()`].
+    pub fn verify_signatures_batch<A, V>(
+        &self,
+        mut additional_signed: A,
+        verify_batch: V,
+    ) -> Result<bool, Error>
+    where
+        A: FnMut(&ExtrinsicDetails<T, C>) -> Result<Vec<u8>, Error>,
+        V: FnOnce(&[SignedPayload<'_>]) -> bool,
+    {
+        let extrinsics = self.iter().collect::<Result<Vec<_>, _>>()?;
+        let mut payloads = Vec::new();
+        for ext in &extrinsics {
+            let (Some(address), Some(signature)) = (ext.address_bytes(), ext.signature_bytes())
+            else {
+                continue;
+            };
+            let additional = additional_signed(ext)?;
+            let payload = ext
+                .signer_payload(&additional)
+                .expect("extrinsic is signed; qed");
+            payloads.push(SignedPayload {
+                address,
+                signature,
+                payload,
+            });
+        }
+        Ok(verify_batch(&payloads))
+    }
 }
 
 /// A single extrinsic in a block.
@@ -199,6 +230,11 @@ where
         self.inner.signed_extensions_bytes()
     }
 
+    /// See [`subxt_core::blocks::ExtrinsicDetails::signer_payload()`].
+    pub fn signer_payload(&self, additional_signed: &[u8]) -> Option<Vec<u8>> {
+        self.inner.signer_payload(additional_signed)
+    }
+
     /// See [`subxt_core::blocks::ExtrinsicDetails::signed_extensions()`].
     pub fn signed_extensions(&self) -> Option<ExtrinsicSignedExtensions<'_, T>> {
         self.inner.signed_extensions()
@@ -234,6 +270,11 @@ where
         self.inner.field_values().map_err(Into::into)
     }
 
+    /// See [`subxt_core::blocks::ExtrinsicDetails::call_tree()`].
+    pub fn call_tree(&self) -> Result<CallTree, Error> {
+        self.inner.call_tree().map_err(Into::into)
+    }
+
     /// See [`subxt_core::blocks::ExtrinsicDetails::as_extrinsic()`].
     pub fn as_extrinsic<E: StaticExtrinsic>(&self) -> Result<Option<E>, Error> {
         self.inner.as_extrinsic::<E>().map_err(Into::into)
@@ -356,3 +397,74 @@ impl<T: Config> ExtrinsicEvents<T> {
         Ok(self.find::<Ev>().next().transpose()?.is_some())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        client::{OfflineClient, RuntimeVersion},
+        config::{ExtrinsicParams, ExtrinsicParamsEncoder, PolkadotConfig},
+        dynamic::Value,
+        utils::H256,
+        Metadata,
+    };
+    use codec::Decode;
+    use subxt_signer::sr25519;
+
+    #[test]
+    fn signatures_can_be_batch_verified() {
+        let metadata_bytes = include_bytes!("../../../artifacts/polkadot_metadata_small.scale");
+        let metadata = Metadata::decode(&mut &metadata_bytes[..]).unwrap();
+        let client = OfflineClient::<PolkadotConfig>::new(
+            H256::repeat_byte(1),
+            RuntimeVersion {
+                spec_version: 1,
+                transaction_version: 1,
+            },
+            metadata,
+        );
+
+        let remark =
+            |bytes: &[u8]| crate::dynamic::tx("System", "remark", vec![Value::from_bytes(bytes)]);
+        let signed = |call, signer: &sr25519::Keypair| {
+            client
+                .tx()
+                .create_signed_offline(&call, signer, Default::default())
+                .unwrap()
+                .into_encoded()
+        };
+        let body = vec![
+            signed(remark(b"hello"), &sr25519::dev::alice()),
+            signed(remark(&[7; 300]), &sr25519::dev::bob()),
+        ];
+
+        // Our transactions are immortal and use default params, so the additional signed
+        // bytes are the same for each one:
+        let mut additional = Vec::new();
+        <PolkadotConfig as Config>::ExtrinsicParams::new(
+            &client.client_state(),
+            Default::default(),
+        )
+        .unwrap()
+        .encode_additional_to(&mut additional);
+
+        let extrinsics =
+            Extrinsics::new(client.clone(), body, Default::default(), H256::zero()).unwrap();
+        let all_valid = extrinsics
+            .verify_signatures_batch(|_| Ok(additional.clone()), sr25519::verify_signed_payloads)
+            .unwrap();
+        assert!(all_valid);
+
+        // With the wrong additional bytes (here, a different spec version), the signed payloads
+        // no longer match what was signed:
+        let mut wrong_additional = additional.clone();
+        wrong_additional[0] ^= 1;
+        let all_valid = extrinsics
+            .verify_signatures_batch(
+                |_| Ok(wrong_additional.clone()),
+                sr25519::verify_signed_payloads,
+            )
+            .unwrap();
+        assert!(!all_valid);
+    }
+}
@@ -4,19 +4,23 @@
 
 //! This module exposes the necessary functionality for working with events.
 
+mod best_block_estimator;
 mod block_types;
 mod blocks_client;
 mod extrinsic_types;
+mod nonce_audit;
 
 /// A reference to a block.
 pub use crate::backend::BlockRef;
 
+pub use best_block_estimator::{expected_block_time, BestBlockEstimator};
 pub use block_types::Block;
 pub use blocks_client::BlocksClient;
 pub use extrinsic_types::{
-    ExtrinsicDetails, ExtrinsicEvents, ExtrinsicSignedExtension, ExtrinsicSignedExtensions,
-    Extrinsics, FoundExtrinsic, StaticExtrinsic,
+    CallTree, ExtrinsicDetails, ExtrinsicEvents, ExtrinsicSignedExtension,
+    ExtrinsicSignedExtensions, Extrinsics, FoundExtrinsic, SignedPayload, StaticExtrinsic,
 };
+pub use nonce_audit::{NonceAudit, NonceUse};
 
 // We get account nonce info in tx_client, too, so re-use the logic:
 pub(crate) use block_types::get_account_nonce;
@@ -0,0 +1,188 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::{
+    blocks::Block,
+    client::OnlineClientT,
+    config::{Config, Header},
+    error::Error,
+};
+
+use codec::Encode;
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// Where in the chain a given nonce was used.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonceUse<Hash> {
+    /// The number of the block containing the extrinsic.
+    pub block_number: u64,
+    /// The hash of the block containing the extrinsic.
+    pub block_hash: Hash,
+    /// The index of the extrinsic in the block.
+    pub extrinsic_index: u32,
+}
+
+/// Audit the nonces used by extrinsics signed by a single account. Hand it each
+/// block in the range of interest via [`NonceAudit::add_block()`], and then inspect
+/// which nonces were used, where they were used, and any gaps or duplicates.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use subxt::{blocks::NonceAudit, OnlineClient, PolkadotConfig};
+/// use subxt_signer::sr25519::dev;
+///
+/// let api = OnlineClient::<PolkadotConfig>::new().await?;
+/// let mut audit = NonceAudit::new(dev::alice().public_key().into());
+///
+/// // Audit the next 100 finalized blocks.
+/// let mut blocks = api.blocks().subscribe_finalized().await?;
+/// for _ in 0..100 {
+///     let Some(block) = blocks.next().await else { break };
+///     audit.add_block(&block?).await?;
+/// }
+///
+/// for (nonce, uses) in audit.duplicates() {
+///     println!("nonce {nonce} used {} times", uses.len());
+/// }
+/// for gap in audit.gaps() {
+///     println!("nonces {gap:?} not seen");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct NonceAudit<T: Config> {
+    address_bytes: Vec<u8>,
+    uses: BTreeMap<u64, Vec<NonceUse<T::Hash>>>,
+}
+
+impl<T: Config> NonceAudit<T> {
+    /// Create a new audit for extrinsics signed by the given account.
+    pub fn new(account_id: T::AccountId) -> Self {
+        let address: T::Address = account_id.into();
+        Self {
+            address_bytes: address.encode(),
+            uses: BTreeMap::new(),
+        }
+    }
+
+    /// Record the nonce of each extrinsic in the given block that was signed by the
+    /// account being audited. Blocks can be added in any order, but adding the same
+    /// block twice will cause each of its extrinsics to be reported as a duplicate.
+    pub async fn add_block<C>(&mut self, block: &Block<T, C>) -> Result<(), Error>
+    where
+        C: OnlineClientT<T>,
+    {
+        let block_number = block.number().into();
+        let block_hash = block.hash();
+
+        for ext in block.extrinsics().await?.iter() {
+            let ext = ext?;
+            if ext.address_bytes() != Some(&self.address_bytes[..]) {
+                continue;
+            }
+            let Some(nonce) = ext.signed_extensions().and_then(|e| e.nonce()) else {
+                continue;
+            };
+            self.record(
+                nonce,
+                NonceUse {
+                    block_number,
+                    block_hash,
+                    extrinsic_index: ext.index(),
+                },
+            );
+        }
+        Ok(())
+    }
+
+    fn record(&mut self, nonce: u64, nonce_use: NonceUse<T::Hash>) {
+        self.uses.entry(nonce).or_default().push(nonce_use);
+    }
+
+    /// Iterate over every nonce seen so far, in ascending order, along with where it was used.
+    pub fn nonces(&self) -> impl Iterator<Item = (u64, &[NonceUse<T::Hash>])> {
+        self.uses.iter().map(|(nonce, uses)| (*nonce, &**uses))
+    }
+
+    /// Return where the given nonce was used. This is empty if it wasn't seen.
+    pub fn uses(&self, nonce: u64) -> &[NonceUse<T::Hash>] {
+        self.uses.get(&nonce).map(|uses| &**uses).unwrap_or(&[])
+    }
+
+    /// The lowest and highest nonce seen so far, or `None` if no extrinsics from the
+    /// account have been seen.
+    pub fn nonce_range(&self) -> Option<RangeInclusive<u64>> {
+        let lowest = *self.uses.keys().next()?;
+        let highest = *self.uses.keys().next_back()?;
+        Some(lowest..=highest)
+    }
+
+    /// Iterate over the nonces that were used by more than one extrinsic.
+    pub fn duplicates(&self) -> impl Iterator<Item = (u64, &[NonceUse<T::Hash>])> {
+        self.nonces().filter(|(_, uses)| uses.len() > 1)
+    }
+
+    /// Return any ranges of nonces that were not seen, between the lowest and highest
+    /// nonce that were.
+    pub fn gaps(&self) -> Vec<RangeInclusive<u64>> {
+        let mut gaps = Vec::new();
+        let mut nonces = self.uses.keys().copied();
+        let Some(mut prev) = nonces.next() else {
+            return gaps;
+        };
+        for nonce in nonces {
+            if nonce > prev + 1 {
+                gaps.push(prev + 1..=nonce - 1);
+            }
+            prev = nonce;
+        }
+        gaps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{config::substrate::H256, utils::AccountId32, SubstrateConfig};
+
+    fn audit_with_nonces(nonces: &[u64]) -> NonceAudit<SubstrateConfig> {
+        let mut audit = NonceAudit::new(AccountId32([1; 32]));
+        for (idx, nonce) in nonces.iter().enumerate() {
+            audit.record(
+                *nonce,
+                NonceUse {
+                    block_number: idx as u64,
+                    block_hash: H256::zero(),
+                    extrinsic_index: 1,
+                },
+            );
+        }
+        audit
+    }
+
+    #[test]
+    fn reports_gaps_between_seen_nonces() {
+        let audit = audit_with_nonces(&[3, 4, 7, 9, 10, 14]);
+        assert_eq!(audit.gaps(), vec![5..=6, 8..=8, 11..=13]);
+        assert_eq!(audit.nonce_range(), Some(3..=14));
+        assert!(audit_with_nonces(&[]).gaps().is_empty());
+    }
+
+    #[test]
+    fn reports_duplicate_nonces() {
+        let audit = audit_with_nonces(&[1, 2, 2, 3, 1, 2]);
+        let duplicates: Vec<_> = audit
+            .duplicates()
+            .map(|(nonce, uses)| (nonce, uses.len()))
+            .collect();
+        assert_eq!(duplicates, vec![(1, 2), (2, 3)]);
+        assert_eq!(audit.uses(2)[1].block_number, 2);
+        assert!(audit.uses(4).is_empty());
+    }
+}
@@ -83,6 +83,18 @@
 #![doc = include_str!("../../../examples/block_decoding_dynamic.rs")]
 //! ```
 //!
+//! ### Summarizing a block
+//!
+//! Since every extrinsic emits an event reporting whether it succeeded or failed, along with the weight
+//! that it used, a single pass over the [events in a block](crate::blocks::Block::events()) is often
+//! enough to compute useful totals for it. The following example computes the fees paid, the weight used
+//! per dispatch class, the number of successful and failed extrinsics and the volume of balance transfers
+//! in each finalized block:
+//!
+//! ```rust,ignore
+#![doc = include_str!("../../../examples/blocks_summary.rs")]
+//! ```
+//!
 //! ##  Decoding signed extensions
 //!
 //! Extrinsics can contain signed extensions. The signed extensions can be different across chains.
@@ -12,7 +12,7 @@ use crate::{
     events::EventsClient,
     runtime_api::RuntimeApiClient,
     storage::StorageClient,
-    tx::TxClient,
+    tx::{TxClient, TxStatusSink},
     Config, Metadata,
 };
 use derive_where::derive_where;
@@ -25,6 +25,12 @@ use subxt_core::client::{ClientState, RuntimeVersion};
 pub trait OnlineClientT<T: Config>: OfflineClientT<T> {
     /// Return a backend that can be used to communicate with a node.
     fn backend(&self) -> &dyn Backend<T>;
+
+    /// Return the sink, if any, which should be told about the status of every transaction
+    /// that's submitted via this client.
+    fn tx_status_sink(&self) -> Option<Arc<dyn TxStatusSink<T>>> {
+        None
+    }
 }
 
 /// A client that can be used to perform API calls (that is, either those
@@ -40,6 +46,8 @@ struct Inner<T: Config> {
     genesis_hash: T::Hash,
     runtime_version: RuntimeVersion,
     metadata: Metadata,
+    #[derive_where(skip)]
+    tx_status_sink: Option<Arc<dyn TxStatusSink<T>>>,
 }
 
 impl<T: Config> std::fmt::Debug for OnlineClient<T> {
@@ -151,13 +159,14 @@ impl<T: Config> OnlineClient<T> {
                 genesis_hash,
                 runtime_version,
                 metadata: metadata.into(),
+                tx_status_sink: None,
             })),
             backend,
         })
     }
 
     /// Fetch the metadata from substrate using the runtime API.
-    async fn fetch_metadata(
+    pub(crate) async fn fetch_metadata(
         backend: &dyn Backend<T>,
         block_hash: T::Hash,
     ) -> Result<Metadata, Error> {
@@ -252,6 +261,16 @@ impl<T: Config> OnlineClient<T> {
         inner.metadata.clone()
     }
 
+    /// Fetch the [`Metadata`] that was in use at the given block. This is useful for decoding
+    /// historic blocks which were produced by an older runtime than the one that this client
+    /// is using. The latest metadata version supported by both Subxt and that runtime is used.
+    ///
+    /// This doesn't change the metadata used by this client; see
+    /// [`OnlineClient::set_metadata()`] for that.
+    pub async fn metadata_at(&self, block_hash: T::Hash) -> Result<Metadata, Error> {
+        OnlineClient::fetch_metadata(&*self.backend, block_hash).await
+    }
+
     /// Change the [`Metadata`] used in this client.
     ///
     /// # Warning
@@ -297,6 +316,21 @@ impl<T: Config> OnlineClient<T> {
         inner.runtime_version = runtime_version;
     }
 
+    /// Register a [`TxStatusSink`] which will be told about every status update of every
+    /// transaction that is submitted via this client (or any clone of it). Transactions which
+    /// are submitted without being watched only report their first status.
+    /// This replaces any sink that was previously registered.
+    pub fn set_tx_status_sink(&self, sink: impl TxStatusSink<T>) {
+        let mut inner = self.inner.write().expect("shouldn't be poisoned");
+        inner.tx_status_sink = Some(Arc::new(sink));
+    }
+
+    /// Remove any [`TxStatusSink`] that was registered with this client.
+    pub fn clear_tx_status_sink(&self) {
+        let mut inner = self.inner.write().expect("shouldn't be poisoned");
+        inner.tx_status_sink = None;
+    }
+
     /// Return an RPC client to make raw requests with.
     pub fn backend(&self) -> &dyn Backend<T> {
         &*self.backend
@@ -376,6 +410,10 @@ impl<T: Config> OnlineClientT<T> for OnlineClient<T> {
     fn backend(&self) -> &dyn Backend<T> {
         &*self.backend
     }
+    fn tx_status_sink(&self) -> Option<Arc<dyn TxStatusSink<T>>> {
+        let inner = self.inner.read().expect("shouldn't be poisoned");
+        inner.tx_status_sink.clone()
+    }
 }
 
 /// Client wrapper for performing runtime updates. See [`OnlineClient::updater()`]
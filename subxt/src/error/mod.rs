@@ -125,6 +125,11 @@ impl Error {
     pub fn is_disconnected_will_reconnect(&self) -> bool {
         matches!(self, Error::Rpc(RpcError::DisconnectedWillReconnect(_)))
     }
+
+    /// Checks whether the error was caused by an RPC request or subscription timing out.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Error::Rpc(RpcError::Timeout))
+    }
 }
 
 /// An RPC error. Since we are generic over the RPC client that is used,
@@ -150,6 +155,14 @@ pub enum RpcError {
     /// The connection was lost and automatically reconnected.
     #[error("RPC error: the connection was lost `{0}`; reconnect automatically initiated")]
     DisconnectedWillReconnect(String),
+    /// The RPC client in use does not support subscriptions (for instance, because it
+    /// talks to the node over HTTP).
+    #[error("RPC error: subscriptions are not supported by this RPC client")]
+    SubscriptionsNotSupported,
+    /// The node took too long to respond to a request, or to send the next item
+    /// on a subscription.
+    #[error("RPC error: timed out waiting for a response")]
+    Timeout,
 }
 
 impl RpcError {
@@ -157,6 +170,40 @@ impl RpcError {
     pub fn request_rejected<S: Into<String>>(s: S) -> RpcError {
         RpcError::RequestRejected(s.into())
     }
+
+    /// Is the error likely to be caused by a problem with the connection to the node, or
+    /// the node being temporarily unavailable, rather than the node rejecting the request?
+    /// Requests which fail with such errors may succeed if they are tried again, or tried
+    /// against another node.
+    ///
+    /// Errors from the underlying client are only considered transient if they come from
+    /// [`jsonrpsee`] and relate to the transport (for instance, a lost connection or a
+    /// request timing out). Other clients should return [`RpcError::SubscriptionDropped`],
+    /// [`RpcError::DisconnectedWillReconnect`] or [`RpcError::Timeout`] as appropriate.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            #[cfg(feature = "jsonrpsee")]
+            RpcError::ClientError(e) => {
+                use jsonrpsee::core::client::Error as JsonrpseeError;
+                matches!(
+                    e.downcast_ref::<JsonrpseeError>(),
+                    Some(
+                        JsonrpseeError::Transport(_)
+                            | JsonrpseeError::RestartNeeded(_)
+                            | JsonrpseeError::RequestTimeout
+                    )
+                )
+            }
+            #[cfg(not(feature = "jsonrpsee"))]
+            RpcError::ClientError(_) => false,
+            RpcError::SubscriptionDropped
+            | RpcError::DisconnectedWillReconnect(_)
+            | RpcError::Timeout => true,
+            RpcError::RequestRejected(_)
+            | RpcError::InsecureUrl(_)
+            | RpcError::SubscriptionsNotSupported => false,
+        }
+    }
 }
 
 /// Block error
@@ -176,6 +223,9 @@ pub enum BlockError {
     /// Decoding error.
     #[error("Cannot decode extrinsic: {0}")]
     DecodingError(codec::Error),
+    /// The call could not be decoded into a pallet variant wrapping a call variant.
+    #[error("Cannot decode call: expected a pallet variant wrapping a call variant")]
+    UnexpectedCallShape,
 }
 
 impl From<CoreBlockError> for BlockError {
@@ -184,6 +234,7 @@ impl From<CoreBlockError> for BlockError {
             CoreBlockError::MissingType => BlockError::MissingType,
             CoreBlockError::UnsupportedVersion(n) => BlockError::UnsupportedVersion(n),
             CoreBlockError::DecodingError(e) => BlockError::DecodingError(e),
+            CoreBlockError::UnexpectedCallShape => BlockError::UnexpectedCallShape,
         }
     }
 }
@@ -213,4 +264,74 @@ pub enum TransactionError {
     /// The transaction was dropped.
     #[error("The transaction was dropped: {0}")]
     Dropped(String),
+    /// The encoded transaction is too large to ever be included in a block.
+    #[error("The transaction is {length} bytes long, which exceeds the maximum block length of {max} bytes")]
+    ExceedsBlockLength {
+        /// The length of the encoded transaction in bytes.
+        length: usize,
+        /// The maximum length of a block in bytes.
+        max: u32,
+    },
+    /// The transaction was not confirmed when previewed, and so was not signed or submitted.
+    #[error("The transaction was not confirmed, so it was not signed or submitted")]
+    NotConfirmed,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn transient_rpc_errors() {
+        let transient = [
+            RpcError::SubscriptionDropped,
+            RpcError::DisconnectedWillReconnect("gone".into()),
+            RpcError::Timeout,
+        ];
+        for e in transient {
+            assert!(e.is_transient(), "{e:?} should be transient");
+        }
+
+        let not_transient = [
+            RpcError::request_rejected("nope"),
+            RpcError::InsecureUrl("ws://example.com".into()),
+            RpcError::SubscriptionsNotSupported,
+            RpcError::ClientError("some other client error".into()),
+        ];
+        for e in not_transient {
+            assert!(!e.is_transient(), "{e:?} should not be transient");
+        }
+    }
+
+    #[cfg(feature = "jsonrpsee")]
+    #[test]
+    fn transient_jsonrpsee_errors() {
+        use jsonrpsee::{core::client::Error as JsonrpseeError, types::ErrorObject};
+        use std::sync::Arc;
+
+        let client_error = |e: JsonrpseeError| RpcError::ClientError(Box::new(e));
+        let transport_error = || {
+            JsonrpseeError::Transport(
+                std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into(),
+            )
+        };
+
+        let transient = [
+            transport_error(),
+            JsonrpseeError::RestartNeeded(Arc::new(transport_error())),
+            JsonrpseeError::RequestTimeout,
+        ];
+        for e in transient {
+            assert!(client_error(e).is_transient());
+        }
+
+        let not_transient = [
+            JsonrpseeError::Call(ErrorObject::owned(-32601, "Method not found", None::<()>)),
+            JsonrpseeError::ParseError(serde_json::from_str::<u8>("x").unwrap_err()),
+            JsonrpseeError::Custom("custom".into()),
+        ];
+        for e in not_transient {
+            assert!(!client_error(e).is_transient());
+        }
+    }
 }
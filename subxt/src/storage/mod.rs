@@ -4,11 +4,15 @@
 
 //! Types associated with accessing and working with storage items.
 
+mod state_snapshot;
 mod storage_client;
+mod storage_diff;
 mod storage_type;
 
+pub use state_snapshot::{SnapshotConfig, StateSnapshot};
 pub use storage_client::StorageClient;
-pub use storage_type::{Storage, StorageKeyValuePair};
+pub use storage_diff::{StorageDiff, ValueChange};
+pub use storage_type::{Storage, StorageChange, StorageKeyValuePair};
 pub use subxt_core::storage::address::{
     dynamic, Address, DefaultAddress, DynamicAddress, StaticAddress, StaticStorageKey, StorageKey,
 };
@@ -0,0 +1,205 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::{error::Error, metadata::Metadata};
+use codec::{Decode, Encode};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use subxt_core::storage::address::Address;
+use subxt_core::utils::Yes;
+
+/// Configure which storage entries are downloaded by [`crate::storage::Storage::snapshot()`].
+#[derive(Clone, Debug, Default)]
+pub struct SnapshotConfig {
+    pub(super) prefixes: Vec<Vec<u8>>,
+    pub(super) cache_path: Option<PathBuf>,
+}
+
+impl SnapshotConfig {
+    /// Create a new, empty [`SnapshotConfig`]. Add at least one pallet or prefix
+    /// to it, else the resulting snapshot will be empty.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Download every storage entry belonging to the pallet with the given name.
+    pub fn pallet(mut self, pallet_name: impl AsRef<str>) -> Self {
+        let prefix = sp_crypto_hashing::twox_128(pallet_name.as_ref().as_bytes());
+        self.prefixes.push(prefix.to_vec());
+        self
+    }
+
+    /// Download every storage entry underneath the raw key prefix given.
+    pub fn prefix(mut self, prefix: impl Into<Vec<u8>>) -> Self {
+        self.prefixes.push(prefix.into());
+        self
+    }
+
+    /// Cache the snapshot in the file at the given path. If the file already
+    /// exists and was written for the same block and prefixes, the snapshot is
+    /// loaded from it rather than being downloaded again. Otherwise, the
+    /// snapshot is downloaded and the file is (over)written.
+    pub fn cache_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cache_path = Some(path.into());
+        self
+    }
+}
+
+/// The raw storage entries under some set of prefixes, as they were at a specific block.
+/// Obtain one via [`crate::storage::Storage::snapshot()`].
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct StateSnapshot<Hash> {
+    block_hash: Hash,
+    prefixes: Vec<Vec<u8>>,
+    entries: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl<Hash: Encode + Decode + PartialEq> StateSnapshot<Hash> {
+    pub(super) fn new(
+        block_hash: Hash,
+        prefixes: Vec<Vec<u8>>,
+        entries: BTreeMap<Vec<u8>, Vec<u8>>,
+    ) -> Self {
+        Self {
+            block_hash,
+            prefixes,
+            entries,
+        }
+    }
+
+    /// The hash of the block that this snapshot was taken at.
+    pub fn block_hash(&self) -> &Hash {
+        &self.block_hash
+    }
+
+    /// The number of storage entries in this snapshot.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Does this snapshot contain no storage entries?
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return the raw value stored at the given key, if it's in the snapshot.
+    pub fn get_raw(&self, key: &[u8]) -> Option<&[u8]> {
+        self.entries.get(key).map(|v| &**v)
+    }
+
+    /// Iterate over all of the raw keys and values in this snapshot.
+    pub fn iter_raw(&self) -> impl Iterator<Item = (&[u8], &[u8])> {
+        self.entries.iter().map(|(k, v)| (&**k, &**v))
+    }
+
+    /// Iterate over the raw keys and values in this snapshot which start with the given prefix.
+    pub fn iter_raw_prefix<'a>(
+        &'a self,
+        prefix: &'a [u8],
+    ) -> impl Iterator<Item = (&'a [u8], &'a [u8])> + 'a {
+        self.entries
+            .range(prefix.to_vec()..)
+            .take_while(move |(k, _)| k.starts_with(prefix))
+            .map(|(k, v)| (&**k, &**v))
+    }
+
+    /// Look up and decode the value at some storage address, using the metadata given
+    /// to do so. Returns `None` if the value isn't in the snapshot.
+    pub fn fetch<Addr>(
+        &self,
+        address: &Addr,
+        metadata: &Metadata,
+    ) -> Result<Option<Addr::Target>, Error>
+    where
+        Addr: Address<IsFetchable = Yes>,
+    {
+        subxt_core::storage::validate(address, metadata)?;
+        let key = subxt_core::storage::get_address_bytes(address, metadata)?;
+        let Some(bytes) = self.entries.get(&key) else {
+            return Ok(None);
+        };
+        let val = subxt_core::storage::decode_value(&mut &**bytes, address, metadata)?;
+        Ok(Some(val))
+    }
+
+    /// Write this snapshot out to the file at the given path.
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        std::fs::write(path, self.encode())?;
+        Ok(())
+    }
+
+    /// Load a snapshot that was previously written using [`StateSnapshot::save_to_file()`].
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let bytes = std::fs::read(path)?;
+        let snapshot = Self::decode(&mut &*bytes)?;
+        Ok(snapshot)
+    }
+
+    /// Load a cached snapshot from the given path if one exists and was taken with the
+    /// same block hash and prefixes that are given.
+    pub(super) fn load_cached(
+        path: &Path,
+        block_hash: &Hash,
+        prefixes: &[Vec<u8>],
+    ) -> Option<Self> {
+        let snapshot = Self::load_from_file(path).ok()?;
+        (snapshot.block_hash == *block_hash && snapshot.prefixes == prefixes).then_some(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn snapshot() -> StateSnapshot<[u8; 4]> {
+        let entries = [
+            (vec![1, 2, 3], vec![10]),
+            (vec![1, 2, 4], vec![11]),
+            (vec![1, 3], vec![12]),
+            (vec![2], vec![13]),
+        ];
+        StateSnapshot::new(
+            [9; 4],
+            vec![vec![1], vec![2]],
+            entries.into_iter().collect(),
+        )
+    }
+
+    /// A path in the temp directory which no other test (in this or any other process)
+    /// will use at the same time.
+    fn temp_path() -> std::path::PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let pid = std::process::id();
+        std::env::temp_dir().join(format!("subxt_state_snapshot_test_{pid}_{n}.scale"))
+    }
+
+    #[test]
+    fn iter_raw_prefix_only_returns_matching_entries() {
+        let snapshot = snapshot();
+        let values: Vec<_> = snapshot
+            .iter_raw_prefix(&[1, 2])
+            .map(|(_, v)| v.to_vec())
+            .collect();
+        assert_eq!(values, vec![vec![10], vec![11]]);
+        assert_eq!(snapshot.iter_raw_prefix(&[3]).count(), 0);
+        assert_eq!(snapshot.get_raw(&[2]), Some(&[13u8][..]));
+    }
+
+    #[test]
+    fn cached_snapshot_must_match_block_and_prefixes() {
+        let snapshot = snapshot();
+        let path = temp_path();
+        snapshot.save_to_file(&path).unwrap();
+
+        let prefixes = vec![vec![1], vec![2]];
+        let loaded = StateSnapshot::load_cached(&path, &[9; 4], &prefixes);
+        assert_eq!(loaded, Some(snapshot));
+        assert!(StateSnapshot::load_cached(&path, &[8; 4], &prefixes).is_none());
+        assert!(StateSnapshot::<[u8; 4]>::load_cached(&path, &[9; 4], &[vec![1]]).is_none());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -2,16 +2,20 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
-use super::storage_type::Storage;
+use super::storage_diff::{diff_values, StorageDiff};
+use super::storage_type::{strip_storage_address_root_bytes, Storage, StorageChange};
 use crate::{
-    backend::BlockRef,
-    client::{OfflineClientT, OnlineClientT},
+    backend::{legacy::LegacyRpcMethods, BackendExt, BlockRef, StreamOfResults},
+    client::{OfflineClientT, OnlineClient, OnlineClientT},
     error::Error,
+    metadata::{DecodeWithMetadata, Metadata},
     Config,
 };
 use derive_where::derive_where;
+use futures::StreamExt;
 use std::{future::Future, marker::PhantomData};
-use subxt_core::storage::address::Address;
+use subxt_core::storage::address::{Address, StorageHashers, StorageKey};
+use subxt_core::utils::Yes;
 
 /// Query the runtime storage.
 #[derive_where(Clone; Client)]
@@ -83,4 +87,342 @@ where
             Ok(Storage::new(client, block_ref))
         }
     }
+
+    /// Subscribe to changes to every storage entry under the given address, decoding each
+    /// change as it arrives. The address can point to a single entry, or (by providing fewer
+    /// keys) to every entry in some map or under some partial key.
+    ///
+    /// This uses [`LegacyRpcMethods::state_subscribe_storage_prefixes()`], so the node filters
+    /// the changes and only those under the address are sent back. As noted there, entries
+    /// which are created after subscribing are not reported. Changes are decoded using the
+    /// current metadata.
+    pub async fn subscribe_changes<Addr>(
+        &self,
+        methods: &LegacyRpcMethods<T>,
+        address: Addr,
+    ) -> Result<StreamOfResults<StorageChange<T::Hash, Addr>>, Error>
+    where
+        Addr: Address + 'static,
+        Addr::Keys: 'static + Sized,
+    {
+        let metadata = self.client.metadata();
+        let (_pallet, entry) = subxt_core::storage::lookup_storage_entry_details(
+            address.pallet_name(),
+            address.entry_name(),
+            &metadata,
+        )?;
+        subxt_core::storage::validate(&address, &metadata)?;
+
+        let entry = entry.entry_type();
+        let return_type_id = entry.value_ty();
+        let hashers = StorageHashers::new(entry, metadata.types())?;
+
+        let prefix = subxt_core::storage::get_address_bytes(&address, &metadata)?;
+        let sub = methods
+            .state_subscribe_storage_prefixes([&prefix[..]], None)
+            .await?;
+
+        let decode_change = move |block_hash: T::Hash,
+                                  key_bytes: Vec<u8>,
+                                  value: Option<Vec<u8>>|
+              -> Result<StorageChange<T::Hash, Addr>, Error> {
+            let value = value
+                .map(|value| {
+                    Addr::Target::decode_with_metadata(&mut &*value, return_type_id, &metadata)
+                })
+                .transpose()?;
+
+            let cursor = &mut &key_bytes[..];
+            strip_storage_address_root_bytes(cursor)?;
+            let keys = <Addr::Keys as StorageKey>::decode_storage_key(
+                cursor,
+                &mut hashers.iter(),
+                metadata.types(),
+            )?;
+
+            Ok(StorageChange {
+                block_hash,
+                key_bytes,
+                keys,
+                value,
+            })
+        };
+
+        let s = sub.flat_map(move |change_set| {
+            let changes: Vec<_> = match change_set {
+                Ok(change_set) => {
+                    let block_hash = change_set.block;
+                    change_set
+                        .changes
+                        .into_iter()
+                        .map(|(key, value)| decode_change(block_hash, key.0, value.map(|v| v.0)))
+                        .collect()
+                }
+                Err(e) => vec![Err(e)],
+            };
+            futures::stream::iter(changes)
+        });
+
+        Ok(StreamOfResults::new(Box::pin(s)))
+    }
+
+    /// Fetch the value at some storage address at two different blocks, and compare them.
+    /// The returned [`StorageDiff`] contains both decoded values, as well as a list of the
+    /// individual fields within the value which differ between the two blocks.
+    ///
+    /// Each value is decoded using the metadata that was in use at its block (see
+    /// [`crate::OnlineClient::metadata_at()`]), so values from either side of a runtime
+    /// upgrade can be compared, as long as both can still be decoded into the target type.
+    /// Metadata is only fetched for blocks whose runtime differs from the one this client
+    /// is using.
+    pub async fn diff<Addr>(
+        &self,
+        address: &Addr,
+        before: impl Into<BlockRef<T::Hash>>,
+        after: impl Into<BlockRef<T::Hash>>,
+    ) -> Result<StorageDiff<Addr::Target>, Error>
+    where
+        Addr: Address<IsFetchable = Yes>,
+    {
+        self.validate(address)?;
+        let before = before.into();
+        let after = after.into();
+
+        let before_spec_version = self.spec_version_at(before.hash()).await?;
+        let after_spec_version = self.spec_version_at(after.hash()).await?;
+        let before_metadata = self
+            .metadata_for_spec_version(before_spec_version, before.hash())
+            .await?;
+        let after_metadata = if after_spec_version == before_spec_version {
+            before_metadata.clone()
+        } else {
+            self.metadata_for_spec_version(after_spec_version, after.hash())
+                .await?
+        };
+
+        let (before, before_value) = self
+            .fetch_for_diff(address, before, &before_metadata)
+            .await?;
+        let (after, after_value) = self.fetch_for_diff(address, after, &after_metadata).await?;
+
+        Ok(StorageDiff {
+            before,
+            after,
+            changes: diff_values(before_value.as_ref(), after_value.as_ref()),
+        })
+    }
+
+    /// The spec version of the runtime in use at the given block.
+    async fn spec_version_at(&self, block_hash: T::Hash) -> Result<u32, Error> {
+        // The start of the `RuntimeVersion` returned from `Core_version`; we only need the spec version.
+        #[derive(codec::Decode)]
+        struct RuntimeVersionPrefix {
+            _spec_name: String,
+            _impl_name: String,
+            _authoring_version: u32,
+            spec_version: u32,
+        }
+
+        let version: RuntimeVersionPrefix = self
+            .client
+            .backend()
+            .call_decoding("Core_version", None, block_hash)
+            .await?;
+        Ok(version.spec_version)
+    }
+
+    /// The metadata for some block with the given spec version. This is the client's own
+    /// metadata if the spec versions match, and is otherwise fetched from the block.
+    async fn metadata_for_spec_version(
+        &self,
+        spec_version: u32,
+        block_hash: T::Hash,
+    ) -> Result<Metadata, Error> {
+        if spec_version == self.client.runtime_version().spec_version {
+            Ok(self.client.metadata())
+        } else {
+            OnlineClient::<T>::fetch_metadata(self.client.backend(), block_hash).await
+        }
+    }
+
+    /// Fetch the value at some storage address at the given block, decoding it both into the
+    /// target type and into a dynamic value to compare, using the metadata given.
+    async fn fetch_for_diff<Addr>(
+        &self,
+        address: &Addr,
+        block_ref: BlockRef<T::Hash>,
+        metadata: &Metadata,
+    ) -> Result<(Option<Addr::Target>, Option<scale_value::Value>), Error>
+    where
+        Addr: Address<IsFetchable = Yes>,
+    {
+        let key = subxt_core::storage::get_address_bytes(address, metadata)?;
+
+        let (_, entry) = subxt_core::storage::lookup_storage_entry_details(
+            address.pallet_name(),
+            address.entry_name(),
+            metadata,
+        )?;
+        let value_ty_id = match entry.entry_type() {
+            subxt_metadata::StorageEntryType::Plain(ty) => *ty,
+            subxt_metadata::StorageEntryType::Map { value_ty, .. } => *value_ty,
+        };
+
+        let Some(bytes) = self.at(block_ref).fetch_raw(key).await? else {
+            return Ok((None, None));
+        };
+        let target = subxt_core::storage::decode_value(&mut &*bytes, address, metadata)?;
+        let value =
+            scale_value::scale::decode_as_type(&mut &*bytes, value_ty_id, metadata.types())?;
+        Ok((Some(target), Some(value.remove_context())))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        backend::{
+            legacy::rpc_methods::{Bytes, StorageChangeSet},
+            rpc::{MockRpcClient, RpcClient},
+        },
+        client::RuntimeVersion,
+        dynamic::Value,
+        utils::H256,
+        Metadata, SubstrateConfig,
+    };
+    use codec::{Decode, Encode};
+
+    #[tokio::test]
+    async fn subscribe_changes_decodes_each_change() {
+        let metadata_bytes = include_bytes!("../../../artifacts/polkadot_metadata_small.scale");
+        let metadata = Metadata::decode(&mut &metadata_bytes[..]).unwrap();
+
+        let block_hash_key = |n: u32| {
+            let address =
+                crate::dynamic::storage("System", "BlockHash", vec![Value::u128(n.into())]);
+            subxt_core::storage::get_address_bytes(&address, &metadata).unwrap()
+        };
+        let key1 = block_hash_key(1);
+        let key2 = block_hash_key(2);
+
+        let keys = [
+            format!("0x{}", hex::encode(&key1)),
+            format!("0x{}", hex::encode(&key2)),
+        ];
+        let change_set = StorageChangeSet {
+            block: H256::repeat_byte(9),
+            changes: vec![
+                (
+                    Bytes(key1.clone()),
+                    Some(Bytes(H256::repeat_byte(1).encode())),
+                ),
+                (Bytes(key2.clone()), None),
+            ],
+        };
+        let mock_client: RpcClient = MockRpcClient::builder()
+            .method_handler("state_getKeysPaged", move |_params| Ok(keys.clone()))
+            .subscription_handler("state_subscribeStorage", move |params| {
+                // Only the keys under the prefix are subscribed to:
+                let keys: Vec<String> = params.get(0)?;
+                assert_eq!(keys.len(), 2);
+                Ok([change_set.clone()])
+            })
+            .build()
+            .into();
+
+        let api = OnlineClient::<SubstrateConfig>::from_rpc_client_with(
+            H256::zero(),
+            RuntimeVersion {
+                spec_version: 1,
+                transaction_version: 1,
+            },
+            metadata,
+            mock_client.clone(),
+        )
+        .unwrap();
+        let methods = LegacyRpcMethods::<SubstrateConfig>::new(mock_client);
+
+        let address = crate::dynamic::storage("System", "BlockHash", Vec::<Value>::new());
+        let changes: Vec<_> = api
+            .storage()
+            .subscribe_changes(&methods, address)
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(changes.len(), 2);
+
+        let first = changes[0].as_ref().unwrap();
+        assert_eq!(first.block_hash, H256::repeat_byte(9));
+        assert_eq!(first.key_bytes, key1);
+        assert_eq!(first.keys[0].as_u128(), Some(1));
+        let value = first
+            .value
+            .as_ref()
+            .unwrap()
+            .to_value()
+            .unwrap()
+            .remove_context();
+        assert_eq!(value, Value::from_bytes(H256::repeat_byte(1)));
+
+        let second = changes[1].as_ref().unwrap();
+        assert_eq!(second.keys[0].as_u128(), Some(2));
+        assert!(second.value.is_none());
+    }
+
+    #[tokio::test]
+    async fn diff_reuses_metadata_for_the_same_runtime() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let metadata_bytes = include_bytes!("../../../artifacts/polkadot_metadata_small.scale");
+        let metadata = Metadata::decode(&mut &metadata_bytes[..]).unwrap();
+        let address = crate::dynamic::storage("System", "Number", ());
+
+        let metadata_calls = Arc::new(AtomicUsize::new(0));
+        let metadata_calls2 = metadata_calls.clone();
+        let mock_client = MockRpcClient::builder()
+            .with_storage(&metadata, &address, &1234u32)
+            .unwrap()
+            .method_handler("state_call", move |params| {
+                let method: String = params.get(0)?;
+                if method != "Core_version" {
+                    metadata_calls2.fetch_add(1, Ordering::SeqCst);
+                    return Err(crate::error::RpcError::request_rejected("unexpected call"));
+                }
+                // The start of a `RuntimeVersion`, with the same spec version as the client:
+                let version = (
+                    String::from("polkadot"),
+                    String::from("parity-polkadot"),
+                    0u32,
+                    1u32,
+                );
+                Ok(Bytes(version.encode()))
+            })
+            .build();
+
+        let api = OnlineClient::<SubstrateConfig>::from_rpc_client_with(
+            H256::zero(),
+            RuntimeVersion {
+                spec_version: 1,
+                transaction_version: 1,
+            },
+            metadata,
+            mock_client,
+        )
+        .unwrap();
+
+        let diff = api
+            .storage()
+            .diff(&address, H256::repeat_byte(1), H256::repeat_byte(2))
+            .await
+            .unwrap();
+        assert!(diff.is_unchanged());
+        assert_eq!(
+            diff.before().unwrap().to_value().unwrap().as_u128(),
+            Some(1234)
+        );
+        assert_eq!(metadata_calls.load(Ordering::SeqCst), 0);
+    }
 }
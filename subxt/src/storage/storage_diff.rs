@@ -0,0 +1,205 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use scale_value::{Composite, Value, ValueDef};
+
+/// The result of comparing the value at some storage address at two different blocks.
+/// Obtain one via [`crate::storage::StorageClient::diff()`].
+#[derive(Clone, Debug)]
+pub struct StorageDiff<V> {
+    pub(super) before: Option<V>,
+    pub(super) after: Option<V>,
+    pub(super) changes: Vec<ValueChange>,
+}
+
+impl<V> StorageDiff<V> {
+    /// The decoded value at the first block, or `None` if there was no value.
+    pub fn before(&self) -> Option<&V> {
+        self.before.as_ref()
+    }
+
+    /// The decoded value at the second block, or `None` if there was no value.
+    pub fn after(&self) -> Option<&V> {
+        self.after.as_ref()
+    }
+
+    /// Each of the individual parts of the value which differ between the two blocks.
+    pub fn changes(&self) -> &[ValueChange] {
+        &self.changes
+    }
+
+    /// Is the value the same at both blocks?
+    pub fn is_unchanged(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// A single difference found between two values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValueChange {
+    /// Where in the value the change was found, for instance `.data.free` for a named field
+    /// or `[2]` for an unnamed one. This is empty if the value as a whole has changed.
+    pub path: String,
+    /// The value at this path before, or `None` if it didn't exist.
+    pub before: Option<Value>,
+    /// The value at this path after, or `None` if it no longer exists.
+    pub after: Option<Value>,
+}
+
+impl std::fmt::Display for ValueChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = if self.path.is_empty() {
+            "."
+        } else {
+            &self.path
+        };
+        match (&self.before, &self.after) {
+            (Some(before), Some(after)) => write!(f, "{path}: {before} -> {after}"),
+            (Some(before), None) => write!(f, "{path}: {before} -> (none)"),
+            (None, Some(after)) => write!(f, "{path}: (none) -> {after}"),
+            (None, None) => write!(f, "{path}: (none)"),
+        }
+    }
+}
+
+/// Compare two optional values, returning each of the parts of them which differ.
+pub(super) fn diff_values(before: Option<&Value>, after: Option<&Value>) -> Vec<ValueChange> {
+    let mut changes = Vec::new();
+    match (before, after) {
+        (Some(before), Some(after)) => diff_into(String::new(), before, after, &mut changes),
+        (None, None) => {}
+        (before, after) => changes.push(ValueChange {
+            path: String::new(),
+            before: before.cloned(),
+            after: after.cloned(),
+        }),
+    }
+    changes
+}
+
+fn diff_into(path: String, before: &Value, after: &Value, changes: &mut Vec<ValueChange>) {
+    if before == after {
+        return;
+    }
+    match (&before.value, &after.value) {
+        (ValueDef::Composite(a), ValueDef::Composite(b)) => {
+            diff_composites(path, a, b, changes);
+        }
+        // Variants with the same name can be compared field by field. If the
+        // variant itself changes, we report the whole value as having changed.
+        (ValueDef::Variant(a), ValueDef::Variant(b)) if a.name == b.name => {
+            diff_composites(path, &a.values, &b.values, changes);
+        }
+        _ => changes.push(ValueChange {
+            path,
+            before: Some(before.clone()),
+            after: Some(after.clone()),
+        }),
+    }
+}
+
+fn diff_composites(
+    path: String,
+    before: &Composite<()>,
+    after: &Composite<()>,
+    changes: &mut Vec<ValueChange>,
+) {
+    match (before, after) {
+        (Composite::Named(a), Composite::Named(b)) => {
+            for (name, a_val) in a {
+                let field_path = format!("{path}.{name}");
+                match b.iter().find(|(n, _)| n == name) {
+                    Some((_, b_val)) => diff_into(field_path, a_val, b_val, changes),
+                    None => changes.push(ValueChange {
+                        path: field_path,
+                        before: Some(a_val.clone()),
+                        after: None,
+                    }),
+                }
+            }
+            for (name, b_val) in b {
+                if !a.iter().any(|(n, _)| n == name) {
+                    changes.push(ValueChange {
+                        path: format!("{path}.{name}"),
+                        before: None,
+                        after: Some(b_val.clone()),
+                    });
+                }
+            }
+        }
+        (Composite::Unnamed(a), Composite::Unnamed(b)) => {
+            for idx in 0..a.len().max(b.len()) {
+                let field_path = format!("{path}[{idx}]");
+                match (a.get(idx), b.get(idx)) {
+                    (Some(a_val), Some(b_val)) => diff_into(field_path, a_val, b_val, changes),
+                    (a_val, b_val) => changes.push(ValueChange {
+                        path: field_path,
+                        before: a_val.cloned(),
+                        after: b_val.cloned(),
+                    }),
+                }
+            }
+        }
+        _ => changes.push(ValueChange {
+            path,
+            before: Some(Value::without_context(ValueDef::Composite(before.clone()))),
+            after: Some(Value::without_context(ValueDef::Composite(after.clone()))),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn account(nonce: u32, free: u128, reserved: u128) -> Value {
+        Value::named_composite([
+            ("nonce", Value::u128(nonce as u128)),
+            (
+                "data",
+                Value::named_composite([
+                    ("free", Value::u128(free)),
+                    ("reserved", Value::u128(reserved)),
+                ]),
+            ),
+        ])
+    }
+
+    #[test]
+    fn reports_changed_fields_by_path() {
+        let before = account(1, 100, 5);
+        let after = account(2, 90, 5);
+
+        let changes = diff_values(Some(&before), Some(&after));
+        let paths: Vec<_> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec![".nonce", ".data.free"]);
+        assert_eq!(changes[1].before, Some(Value::u128(100)));
+        assert_eq!(changes[1].after, Some(Value::u128(90)));
+
+        assert!(diff_values(Some(&before), Some(&before)).is_empty());
+    }
+
+    #[test]
+    fn reports_added_removed_and_replaced_values() {
+        let value = account(1, 100, 5);
+        let changes = diff_values(None, Some(&value));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "");
+        assert_eq!(changes[0].before, None);
+
+        let before = Value::unnamed_composite([Value::u128(1), Value::u128(2)]);
+        let after = Value::unnamed_composite([Value::u128(1), Value::u128(3), Value::u128(4)]);
+        let paths: Vec<_> = diff_values(Some(&before), Some(&after))
+            .into_iter()
+            .map(|c| c.path)
+            .collect();
+        assert_eq!(paths, vec!["[1]", "[2]"]);
+
+        let before = Value::unnamed_variant("Some", [Value::u128(1)]);
+        let after = Value::unnamed_variant("None", []);
+        let changes = diff_values(Some(&before), Some(&after));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "");
+    }
+}
@@ -2,6 +2,7 @@
 // This file is dual-licensed as Apache-2.0 or GPL-3.0.
 // see LICENSE for license details.
 
+use super::state_snapshot::{SnapshotConfig, StateSnapshot};
 use crate::{
     backend::{BackendExt, BlockRef},
     client::OnlineClientT,
@@ -11,8 +12,8 @@ use crate::{
 };
 use codec::Decode;
 use derive_where::derive_where;
-use futures::StreamExt;
-use std::{future::Future, marker::PhantomData};
+use futures::{future, StreamExt, TryStreamExt};
+use std::{collections::BTreeMap, future::Future, marker::PhantomData};
 use subxt_core::storage::address::{Address, StorageHashers, StorageKey};
 use subxt_core::utils::Yes;
 
@@ -290,6 +291,51 @@ where
         u16::decode(&mut &storage_version_bytes[..]).map_err(Into::into)
     }
 
+    /// Download every storage entry underneath the pallets and prefixes given in the
+    /// [`SnapshotConfig`] at this block, returning them as a [`StateSnapshot`] which can
+    /// be inspected offline. Each prefix is fetched concurrently.
+    ///
+    /// If [`SnapshotConfig::cache_path()`] was set and a snapshot for the same block and
+    /// prefixes was previously cached there, it will be loaded from disk instead.
+    pub async fn snapshot(&self, config: SnapshotConfig) -> Result<StateSnapshot<T::Hash>, Error> {
+        let block_hash = self.block_ref.hash();
+        let SnapshotConfig {
+            prefixes,
+            cache_path,
+        } = config;
+
+        if let Some(path) = &cache_path {
+            if let Some(snapshot) = StateSnapshot::load_cached(path, &block_hash, &prefixes) {
+                return Ok(snapshot);
+            }
+        }
+
+        let fetches = prefixes.iter().map(|prefix| {
+            let client = self.client.clone();
+            let prefix = prefix.clone();
+            async move {
+                client
+                    .backend()
+                    .storage_fetch_descendant_values(prefix, block_hash)
+                    .await?
+                    .map(|kv| kv.map(|kv| (kv.key, kv.value)))
+                    .try_collect::<Vec<_>>()
+                    .await
+            }
+        });
+
+        let mut entries = BTreeMap::new();
+        for kvs in future::try_join_all(fetches).await? {
+            entries.extend(kvs);
+        }
+
+        let snapshot = StateSnapshot::new(block_hash, prefixes, entries);
+        if let Some(path) = &cache_path {
+            snapshot.save_to_file(path)?;
+        }
+        Ok(snapshot)
+    }
+
     /// Fetch the runtime WASM code.
     pub async fn runtime_wasm_code(&self) -> Result<Vec<u8>, Error> {
         // note: this should match the `CODE` constant in `sp_core::storage::well_known_keys`
@@ -301,7 +347,9 @@ where
 }
 
 /// Strips the first 32 bytes (16 for the pallet hash, 16 for the entry hash) off some storage address bytes.
-fn strip_storage_address_root_bytes(address_bytes: &mut &[u8]) -> Result<(), StorageAddressError> {
+pub(super) fn strip_storage_address_root_bytes(
+    address_bytes: &mut &[u8],
+) -> Result<(), StorageAddressError> {
     if address_bytes.len() >= 32 {
         *address_bytes = &address_bytes[32..];
         Ok(())
@@ -321,3 +369,16 @@ pub struct StorageKeyValuePair<T: Address> {
     /// The value of the storage entry.
     pub value: T::Target,
 }
+
+/// A change to a single storage entry, as reported by [`super::StorageClient::subscribe_changes()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageChange<Hash, T: Address> {
+    /// The hash of the block that the change was made in.
+    pub block_hash: Hash,
+    /// The bytes that make up the address of the storage entry.
+    pub key_bytes: Vec<u8>,
+    /// The keys that can be used to construct the address of this storage entry.
+    pub keys: T::Keys,
+    /// The new value of the storage entry, or `None` if the entry was removed.
+    pub value: Option<T::Target>,
+}
@@ -12,7 +12,10 @@
 use crate::macros::cfg_substrate_compat;
 
 mod tx_client;
+mod tx_preview;
 mod tx_progress;
+mod tx_queue;
+mod tx_status_sink;
 
 // The PairSigner impl currently relies on Substrate bits and pieces, so make it an optional
 // feature if we want to avoid needing sp_core and sp_runtime.
@@ -26,4 +29,7 @@ pub use tx_client::{
     PartialExtrinsic, SubmittableExtrinsic, TransactionInvalid, TransactionUnknown, TxClient,
     ValidationResult,
 };
+pub use tx_preview::TxPreview;
 pub use tx_progress::{TxInBlock, TxProgress, TxStatus};
+pub use tx_queue::{TxPriority, TxQueue};
+pub use tx_status_sink::TxStatusSink;
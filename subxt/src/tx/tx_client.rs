@@ -4,10 +4,11 @@
 
 use crate::{
     backend::{BackendExt, BlockRef, TransactionStatus},
+    blocks::CallTree,
     client::{OfflineClientT, OnlineClientT},
     config::{Config, ExtrinsicParams, Header, RefineParams, RefineParamsData},
-    error::{BlockError, Error},
-    tx::{Payload, Signer as SignerT, TxProgress},
+    error::{BlockError, Error, TransactionError},
+    tx::{Payload, Signer as SignerT, TxPreview, TxProgress, TxQueue},
     utils::PhantomDataSendSync,
 };
 use codec::{Compact, Decode, Encode};
@@ -104,6 +105,12 @@ impl<T: Config, C: OfflineClientT<T>> TxClient<T, C> {
             })
             .map_err(Into::into)
     }
+
+    /// Create an empty [`TxQueue`], which can be used to submit a number of transactions
+    /// from the same signer, with operational ones being prioritized over normal ones.
+    pub fn queue(&self) -> TxQueue<T, C> {
+        TxQueue::new(self.client.clone())
+    }
 }
 
 impl<T, C> TxClient<T, C>
@@ -221,6 +228,94 @@ where
             .await
     }
 
+    /// Describe the given call in a human readable way, along with an estimate of the fee
+    /// that it will cost to execute. This can be shown to a user so that they know exactly
+    /// what they are being asked to sign.
+    ///
+    /// This assumes that the chain's balance type is a `u128`. Use [`TxClient::preview_as()`]
+    /// if that's not the case.
+    pub async fn preview<Call>(&self, call: &Call) -> Result<TxPreview, Error>
+    where
+        Call: Payload,
+    {
+        self.preview_as::<Call, u128>(call).await
+    }
+
+    /// Like [`TxClient::preview()`], but decodes the fee into the given balance type.
+    pub async fn preview_as<Call, Balance>(&self, call: &Call) -> Result<TxPreview<Balance>, Error>
+    where
+        Call: Payload,
+        Balance: Decode,
+    {
+        let metadata = self.client.metadata();
+        let call_data = self.call_data(call)?;
+
+        let call_tree = CallTree::decode(&call_data, &metadata)?;
+
+        // The signature and signed extensions aren't known yet, so the fee is estimated
+        // from the call alone.
+        let mut params = call_data.clone();
+        (call_data.len() as u32).encode_to(&mut params);
+        let latest_block_ref = self.client.backend().latest_finalized_block_ref().await?;
+        let (_, _, _, partial_fee) = self
+            .client
+            .backend()
+            .call_decoding::<(Compact<u64>, Compact<u64>, u8, Balance)>(
+                "TransactionPaymentCallApi_query_call_info",
+                Some(&params),
+                latest_block_ref.hash(),
+            )
+            .await?;
+
+        Ok(TxPreview::new(call_tree, partial_fee))
+    }
+
+    /// Like [`TxClient::sign_and_submit_then_watch()`], but first hands a [`TxPreview`] of the
+    /// call (see [`TxClient::preview()`]) to `confirm`. The transaction is only signed and
+    /// submitted if this returns `true`; otherwise [`TransactionError::NotConfirmed`] is returned.
+    ///
+    /// This assumes that the chain's balance type is a `u128`. Use
+    /// [`TxClient::sign_and_submit_then_watch_confirmed_as()`] if that's not the case.
+    pub async fn sign_and_submit_then_watch_confirmed<Call, Signer, F>(
+        &self,
+        call: &Call,
+        signer: &Signer,
+        params: <T::ExtrinsicParams as ExtrinsicParams<T>>::Params,
+        confirm: F,
+    ) -> Result<TxProgress<T, C>, Error>
+    where
+        Call: Payload,
+        Signer: SignerT<T>,
+        F: FnOnce(&TxPreview) -> bool,
+    {
+        self.sign_and_submit_then_watch_confirmed_as::<Call, Signer, u128, F>(
+            call, signer, params, confirm,
+        )
+        .await
+    }
+
+    /// Like [`TxClient::sign_and_submit_then_watch_confirmed()`], but decodes the fee in the
+    /// [`TxPreview`] into the given balance type.
+    pub async fn sign_and_submit_then_watch_confirmed_as<Call, Signer, Balance, F>(
+        &self,
+        call: &Call,
+        signer: &Signer,
+        params: <T::ExtrinsicParams as ExtrinsicParams<T>>::Params,
+        confirm: F,
+    ) -> Result<TxProgress<T, C>, Error>
+    where
+        Call: Payload,
+        Signer: SignerT<T>,
+        Balance: Decode,
+        F: FnOnce(&TxPreview<Balance>) -> bool,
+    {
+        let preview = self.preview_as::<Call, Balance>(call).await?;
+        if !confirm(&preview) {
+            return Err(TransactionError::NotConfirmed.into());
+        }
+        self.sign_and_submit_then_watch(call, signer, params).await
+    }
+
     /// Creates and signs an extrinsic and submits to the chain for block inclusion. Passes
     /// default parameters to construct the "signed extra" and "additional" payloads needed
     /// by the extrinsic.
@@ -363,6 +458,39 @@ where
     pub fn into_encoded(self) -> Vec<u8> {
         self.inner.into_encoded()
     }
+
+    /// Check that the encoded extrinsic is no longer than the maximum block length given
+    /// by the `System::BlockLength` constant in the metadata. An extrinsic longer than this
+    /// can never be included in a block, and so will be rejected by the node.
+    ///
+    /// If the metadata does not contain this constant, or it can't be decoded, then no check
+    /// is performed.
+    pub fn validate_length(&self) -> Result<(), Error> {
+        let metadata = self.client.metadata();
+        let Some(block_length) = metadata
+            .pallet_by_name("System")
+            .and_then(|p| p.constant_by_name("BlockLength"))
+        else {
+            return Ok(());
+        };
+
+        // `BlockLength` holds the max length of a block for each dispatch class (normal,
+        // operational and mandatory). We don't know which class this extrinsic belongs to,
+        // so only reject it if it's too large to fit into a block for any of them. If the
+        // constant isn't shaped the way we expect, we don't know the limit, so don't check.
+        let Ok((normal, operational, mandatory)) =
+            <(u32, u32, u32)>::decode(&mut block_length.value())
+        else {
+            return Ok(());
+        };
+        let max = normal.max(operational).max(mandatory);
+
+        let length = self.encoded().len();
+        if length > max as usize {
+            return Err(TransactionError::ExceedsBlockLength { length, max }.into());
+        }
+        Ok(())
+    }
 }
 
 impl<T, C> SubmittableExtrinsic<T, C>
@@ -375,6 +503,9 @@ where
     /// Returns a [`TxProgress`], which can be used to track the status of the transaction
     /// and obtain details about it, once it has made it into a block.
     pub async fn submit_and_watch(&self) -> Result<TxProgress<T, C>, Error> {
+        // Don't bother submitting extrinsics that can never fit into a block.
+        self.validate_length()?;
+
         // Get a hash of the extrinsic (we'll need this later).
         let ext_hash = self.hash();
 
@@ -385,7 +516,11 @@ where
             .submit_transaction(self.encoded())
             .await?;
 
-        Ok(TxProgress::new(sub, self.client.clone(), ext_hash))
+        let progress = TxProgress::new(sub, self.client.clone(), ext_hash);
+        Ok(match self.client.tx_status_sink() {
+            Some(sink) => progress.with_status_sink(sink),
+            None => progress,
+        })
     }
 
     /// Submits the extrinsic to the chain for block inclusion.
@@ -394,6 +529,8 @@ where
     /// submission and whether it's eventually successful or not. This call does not guarantee
     /// success, and is just sending the transaction to the chain.
     pub async fn submit(&self) -> Result<T::Hash, Error> {
+        self.validate_length()?;
+
         let ext_hash = self.hash();
         let mut sub = self
             .client
@@ -403,22 +540,27 @@ where
 
         // If we get a bad status or error back straight away then error, else return the hash.
         match sub.next().await {
-            Some(Ok(status)) => match status {
-                TransactionStatus::Validated
-                | TransactionStatus::Broadcasted { .. }
-                | TransactionStatus::InBestBlock { .. }
-                | TransactionStatus::NoLongerInBestBlock
-                | TransactionStatus::InFinalizedBlock { .. } => Ok(ext_hash),
-                TransactionStatus::Error { message } => {
-                    Err(Error::Other(format!("Transaction error: {message}")))
-                }
-                TransactionStatus::Invalid { message } => {
-                    Err(Error::Other(format!("Transaction invalid: {message}")))
+            Some(Ok(status)) => {
+                if let Some(sink) = self.client.tx_status_sink() {
+                    sink.on_status(ext_hash, &status);
                 }
-                TransactionStatus::Dropped { message } => {
-                    Err(Error::Other(format!("Transaction dropped: {message}")))
+                match status {
+                    TransactionStatus::Validated
+                    | TransactionStatus::Broadcasted { .. }
+                    | TransactionStatus::InBestBlock { .. }
+                    | TransactionStatus::NoLongerInBestBlock
+                    | TransactionStatus::InFinalizedBlock { .. } => Ok(ext_hash),
+                    TransactionStatus::Error { message } => {
+                        Err(Error::Other(format!("Transaction error: {message}")))
+                    }
+                    TransactionStatus::Invalid { message } => {
+                        Err(Error::Other(format!("Transaction invalid: {message}")))
+                    }
+                    TransactionStatus::Dropped { message } => {
+                        Err(Error::Other(format!("Transaction dropped: {message}")))
+                    }
                 }
-            },
+            }
             Some(Err(e)) => Err(e),
             None => Err(Error::Other(
                 "Transaction broadcast was unsuccessful; stream terminated early".into(),
@@ -466,17 +608,27 @@ where
 
     /// This returns an estimate for what the extrinsic is expected to cost to execute, less any tips.
     /// The actual amount paid can vary from block to block based on node traffic and other factors.
+    ///
+    /// This assumes that the chain's balance type is a `u128`. Use
+    /// [`SubmittableExtrinsic::partial_fee_estimate_as()`] if that's not the case.
     pub async fn partial_fee_estimate(&self) -> Result<u128, Error> {
+        self.partial_fee_estimate_as::<u128>().await
+    }
+
+    /// Like [`SubmittableExtrinsic::partial_fee_estimate()`], but decodes the fee into the
+    /// given balance type. This is useful for chains whose balance type is not a `u128`
+    /// (for instance, a `U256` or some custom wrapper type).
+    pub async fn partial_fee_estimate_as<Balance: Decode>(&self) -> Result<Balance, Error> {
         let mut params = self.encoded().to_vec();
         (self.encoded().len() as u32).encode_to(&mut params);
         let latest_block_ref = self.client.backend().latest_finalized_block_ref().await?;
 
         // destructuring RuntimeDispatchInfo, see type information <https://paritytech.github.io/substrate/master/pallet_transaction_payment_rpc_runtime_api/struct.RuntimeDispatchInfo.html>
-        // data layout: {weight_ref_time: Compact<u64>, weight_proof_size: Compact<u64>, class: u8, partial_fee: u128}
+        // data layout: {weight_ref_time: Compact<u64>, weight_proof_size: Compact<u64>, class: u8, partial_fee: Balance}
         let (_, _, _, partial_fee) = self
             .client
             .backend()
-            .call_decoding::<(Compact<u64>, Compact<u64>, u8, u128)>(
+            .call_decoding::<(Compact<u64>, Compact<u64>, u8, Balance)>(
                 "TransactionPaymentApi_query_info",
                 Some(&params),
                 latest_block_ref.hash(),
@@ -484,6 +636,34 @@ where
             .await?;
         Ok(partial_fee)
     }
+
+    /// This returns the part of the fee which is due to the encoded length of the extrinsic.
+    /// It's a part of the fee returned by [`SubmittableExtrinsic::partial_fee_estimate()`],
+    /// and is useful for seeing how much a large payload is contributing to that fee.
+    ///
+    /// This assumes that the chain's balance type is a `u128`. Use
+    /// [`SubmittableExtrinsic::length_fee_estimate_as()`] if that's not the case.
+    pub async fn length_fee_estimate(&self) -> Result<u128, Error> {
+        self.length_fee_estimate_as::<u128>().await
+    }
+
+    /// Like [`SubmittableExtrinsic::length_fee_estimate()`], but decodes the fee into the
+    /// given balance type.
+    pub async fn length_fee_estimate_as<Balance: Decode>(&self) -> Result<Balance, Error> {
+        let params = (self.encoded().len() as u32).encode();
+        let latest_block_ref = self.client.backend().latest_finalized_block_ref().await?;
+
+        let length_fee = self
+            .client
+            .backend()
+            .call_decoding::<Balance>(
+                "TransactionPaymentApi_query_length_to_fee",
+                Some(&params),
+                latest_block_ref.hash(),
+            )
+            .await?;
+        Ok(length_fee)
+    }
 }
 
 impl ValidationResult {
@@ -627,6 +807,47 @@ pub enum TransactionInvalid {
 mod test {
     use super::*;
 
+    #[tokio::test]
+    async fn submit_tells_status_sink() {
+        use crate::{backend::rpc::MockRpcClient, client::RuntimeVersion, utils::H256};
+        use crate::{Metadata, OnlineClient, SubstrateConfig};
+        use std::sync::{Arc, Mutex};
+
+        let metadata_bytes = include_bytes!("../../../artifacts/polkadot_metadata_small.scale");
+        let metadata = Metadata::decode(&mut &metadata_bytes[..]).unwrap();
+
+        let mock_client = MockRpcClient::builder()
+            .subscription_handler("author_submitAndWatchExtrinsic", |_params| {
+                Ok([
+                    serde_json::json!("ready"),
+                    serde_json::json!({ "broadcast": ["peer"] }),
+                ])
+            })
+            .build();
+
+        let api = OnlineClient::<SubstrateConfig>::from_rpc_client_with(
+            H256::zero(),
+            RuntimeVersion {
+                spec_version: 1,
+                transaction_version: 1,
+            },
+            metadata,
+            mock_client,
+        )
+        .unwrap();
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen2 = seen.clone();
+        api.set_tx_status_sink(move |_hash: H256, status: &TransactionStatus<H256>| {
+            seen2.lock().unwrap().push(status.clone());
+        });
+
+        // Only the first status is read when we submit without watching:
+        let tx = SubmittableExtrinsic::from_bytes(api, vec![1, 2, 3]);
+        tx.submit().await.unwrap();
+        assert_eq!(*seen.lock().unwrap(), vec![TransactionStatus::Validated]);
+    }
+
     #[test]
     fn transaction_validity_decoding_empty_bytes() {
         // No panic should occur decoding empty bytes.
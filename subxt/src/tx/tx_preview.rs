@@ -0,0 +1,96 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::blocks::CallTree;
+use scale_value::{Composite, Value, ValueDef};
+
+/// A human readable description of a transaction, which can be shown to a user so that
+/// they can review it before it is signed. Obtain one via [`crate::tx::TxClient::preview()`].
+///
+/// The fee is a `u128` by default. Use [`crate::tx::TxClient::preview_as()`] to obtain a
+/// preview with some other balance type.
+#[derive(Clone, Debug)]
+pub struct TxPreview<Balance = u128> {
+    call: CallTree,
+    partial_fee_estimate: Balance,
+}
+
+impl<Balance> TxPreview<Balance> {
+    /// Build a preview from a decoded call.
+    pub(crate) fn new(call: CallTree, partial_fee_estimate: Balance) -> Self {
+        TxPreview {
+            call,
+            partial_fee_estimate,
+        }
+    }
+
+    /// The name of the pallet that the call belongs to.
+    pub fn pallet_name(&self) -> &str {
+        &self.call.pallet_name
+    }
+
+    /// The name of the call.
+    pub fn call_name(&self) -> &str {
+        &self.call.call_name
+    }
+
+    /// The decoded arguments given to the call.
+    pub fn fields(&self) -> &Composite<u32> {
+        &self.call.fields
+    }
+
+    /// The decoded call, including any calls nested inside it (for instance, the calls
+    /// in a `Utility::batch`).
+    pub fn call_tree(&self) -> &CallTree {
+        &self.call
+    }
+
+    /// An estimate of the fee that will be paid to execute the call, less any tips. This
+    /// is based only on the call and its encoded length, since the preview is made before
+    /// the transaction is signed, and so it will slightly underestimate the final fee, which
+    /// also depends on the length of the signature and signed extensions.
+    pub fn partial_fee_estimate(&self) -> &Balance {
+        &self.partial_fee_estimate
+    }
+}
+
+impl<Balance: std::fmt::Display> std::fmt::Display for TxPreview<Balance> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fields = Value {
+            value: ValueDef::Composite(self.call.fields.clone()),
+            context: 0,
+        }
+        .remove_context();
+        writeln!(
+            f,
+            "{}.{} {fields}",
+            self.call.pallet_name, self.call.call_name
+        )?;
+        write!(f, "Estimated fee: {}", self.partial_fee_estimate)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn preview_from_call_tree() {
+        let call = CallTree {
+            pallet_name: "Balances".into(),
+            call_name: "transfer_allow_death".into(),
+            fields: Composite::named([("value", Value::u128(10_000).map_context(|_| 0))]),
+            inner_calls: vec![],
+        };
+
+        let preview = TxPreview::new(call, 123);
+        assert_eq!(preview.pallet_name(), "Balances");
+        assert_eq!(preview.call_name(), "transfer_allow_death");
+        assert_eq!(preview.fields().len(), 1);
+        assert_eq!(*preview.partial_fee_estimate(), 123u128);
+        assert!(preview
+            .to_string()
+            .starts_with("Balances.transfer_allow_death"));
+    }
+}
@@ -4,6 +4,7 @@
 
 //! Types representing extrinsics/transactions that have been submitted to a node.
 
+use std::sync::Arc;
 use std::task::Poll;
 
 use crate::{
@@ -11,6 +12,7 @@ use crate::{
     client::OnlineClientT,
     error::{DispatchError, Error, RpcError, TransactionError},
     events::EventsClient,
+    tx::TxStatusSink,
     Config,
 };
 use derive_where::derive_where;
@@ -22,6 +24,7 @@ pub struct TxProgress<T: Config, C> {
     sub: Option<StreamOfResults<BackendTxStatus<T::Hash>>>,
     ext_hash: T::Hash,
     client: C,
+    status_sink: Option<Arc<dyn TxStatusSink<T>>>,
 }
 
 impl<T: Config, C> std::fmt::Debug for TxProgress<T, C> {
@@ -50,9 +53,16 @@ impl<T: Config, C> TxProgress<T, C> {
             sub: Some(sub),
             client,
             ext_hash,
+            status_sink: None,
         }
     }
 
+    /// Tell the given [`TxStatusSink`] about each status update as it's received.
+    pub fn with_status_sink(mut self, sink: Arc<dyn TxStatusSink<T>>) -> Self {
+        self.status_sink = Some(sink);
+        self
+    }
+
     /// Return the hash of the extrinsic.
     pub fn extrinsic_hash(&self) -> T::Hash {
         self.ext_hash
@@ -133,6 +143,9 @@ impl<T: Config, C: Clone> Stream for TxProgress<T, C> {
         };
 
         sub.poll_next_unpin(cx).map_ok(|status| {
+            if let Some(sink) = &self.status_sink {
+                sink.on_status(self.ext_hash, &status);
+            }
             match status {
                 BackendTxStatus::Validated => TxStatus::Validated,
                 BackendTxStatus::Broadcasted { num_peers } => TxStatus::Broadcasted { num_peers },
@@ -405,6 +418,28 @@ mod test {
         ));
     }
 
+    #[tokio::test]
+    async fn status_sink_sees_every_status() {
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen2 = seen.clone();
+        let sink = move |_hash: MockHash, status: &MockSubstrateTxStatus| {
+            seen2.lock().unwrap().push(status.clone());
+        };
+
+        let statuses = vec![
+            MockSubstrateTxStatus::Validated,
+            MockSubstrateTxStatus::Broadcasted { num_peers: 2 },
+            MockSubstrateTxStatus::Dropped {
+                message: "err".into(),
+            },
+        ];
+        let tx_progress =
+            mock_tx_progress(statuses.clone()).with_status_sink(std::sync::Arc::new(sink));
+        let _ = tx_progress.wait_for_finalized().await;
+
+        assert_eq!(*seen.lock().unwrap(), statuses);
+    }
+
     fn mock_tx_progress(statuses: Vec<MockSubstrateTxStatus>) -> MockTxProgress {
         let sub = create_substrate_tx_status_subscription(statuses);
         TxProgress::new(sub, MockClient, Default::default())
@@ -0,0 +1,286 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::{
+    client::{OfflineClientT, OnlineClientT},
+    config::{Config, ExtrinsicParams, Header, RefineParams, RefineParamsData},
+    error::{BlockError, Error},
+    metadata::Metadata,
+    tx::{Payload, Signer as SignerT, TxClient, TxProgress},
+};
+use std::cmp::Reverse;
+
+/// The priority of a transaction added to a [`TxQueue`]. Transactions with a higher
+/// priority are given lower nonces, and are submitted first.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TxPriority {
+    /// Bulk transactions which can wait, such as a batch of payouts.
+    #[default]
+    Normal,
+    /// Urgent transactions, such as key rotations, which should not wait
+    /// behind any normal priority transactions from the same signer.
+    Operational,
+}
+
+/// A client side queue of transactions which will all be signed by the same signer.
+///
+/// Transactions are added with some [`TxPriority`], and when the queue is submitted,
+/// operational transactions are given the next available nonces ahead of any normal
+/// ones, so that they are not held up behind them in the transaction pool. Transactions
+/// with the same priority are submitted in the order that they were added.
+///
+/// Obtain one of these via [`TxClient::queue()`].
+pub struct TxQueue<T: Config, C> {
+    client: C,
+    queued: Vec<QueuedTx<T>>,
+}
+
+struct QueuedTx<T: Config> {
+    priority: TxPriority,
+    call_data: Vec<u8>,
+    params: <T::ExtrinsicParams as ExtrinsicParams<T>>::Params,
+}
+
+impl<T: Config, C: OfflineClientT<T>> TxQueue<T, C> {
+    pub(crate) fn new(client: C) -> Self {
+        Self {
+            client,
+            queued: Vec::new(),
+        }
+    }
+
+    /// Add a transaction to the queue with default parameters. The call is validated
+    /// against the current metadata and encoded straight away.
+    pub fn push_default<Call>(&mut self, call: &Call, priority: TxPriority) -> Result<(), Error>
+    where
+        Call: Payload,
+        <T::ExtrinsicParams as ExtrinsicParams<T>>::Params: Default,
+    {
+        self.push(call, priority, Default::default())
+    }
+
+    /// Add a transaction to the queue. The call is validated against the current
+    /// metadata and encoded straight away. The parameters given should not set a
+    /// nonce, since the queue assigns nonces when it is submitted.
+    pub fn push<Call>(
+        &mut self,
+        call: &Call,
+        priority: TxPriority,
+        params: <T::ExtrinsicParams as ExtrinsicParams<T>>::Params,
+    ) -> Result<(), Error>
+    where
+        Call: Payload,
+    {
+        let tx_client = TxClient::<T, C>::new(self.client.clone());
+        tx_client.validate(call)?;
+        let call_data = tx_client.call_data(call)?;
+
+        self.queued.push(QueuedTx {
+            priority,
+            call_data,
+            params,
+        });
+        Ok(())
+    }
+
+    /// The number of transactions waiting in the queue.
+    pub fn len(&self) -> usize {
+        self.queued.len()
+    }
+
+    /// Is the queue empty?
+    pub fn is_empty(&self) -> bool {
+        self.queued.is_empty()
+    }
+
+    /// Sort the queued transactions into the order that they would be submitted in.
+    fn sort_into_submission_order(&mut self) {
+        // This is a stable sort, so transactions with equal priority stay in the order they were added.
+        self.queued.sort_by_key(|tx| Reverse(tx.priority));
+    }
+}
+
+impl<T: Config, C: OnlineClientT<T>> TxQueue<T, C> {
+    /// Sign and submit every transaction in the queue, highest priority first, assigning
+    /// consecutive nonces starting from the signer's current account nonce.
+    ///
+    /// Returns a [`TxProgress`] for each transaction, in the order that they were submitted.
+    /// Transactions are removed from the queue only once they have been submitted. If some
+    /// step fails, an error is returned and the transaction that failed, along with any after
+    /// it, remain in the queue so that they can be submitted again later.
+    pub async fn sign_and_submit_then_watch<Signer>(
+        &mut self,
+        signer: &Signer,
+    ) -> Result<Vec<TxProgress<T, C>>, Error>
+    where
+        Signer: SignerT<T>,
+        <T::ExtrinsicParams as ExtrinsicParams<T>>::Params: Clone,
+    {
+        self.sort_into_submission_order();
+        if self.queued.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let tx_client = TxClient::<T, C>::new(self.client.clone());
+        let block_ref = self.client.backend().latest_finalized_block_ref().await?;
+        let block_header = self
+            .client
+            .backend()
+            .block_header(block_ref.hash())
+            .await?
+            .ok_or_else(|| Error::Block(BlockError::not_found(block_ref.hash())))?;
+        let mut nonce =
+            crate::blocks::get_account_nonce(&self.client, &signer.account_id(), block_ref.hash())
+                .await?;
+
+        let mut progress = Vec::with_capacity(self.queued.len());
+        while let Some(tx) = self.queued.first() {
+            // Refine a copy of the params, so that the queued transaction is left untouched
+            // if we fail to submit it.
+            let mut params = tx.params.clone();
+            params.refine(&RefineParamsData::new(
+                nonce,
+                block_header.number().into(),
+                block_header.hash(),
+            ));
+            let call = EncodedCall(&tx.call_data);
+            let signed = tx_client
+                .create_partial_signed_offline(&call, params)?
+                .sign(signer);
+            progress.push(signed.submit_and_watch().await?);
+
+            self.queued.remove(0);
+            nonce += 1;
+        }
+        Ok(progress)
+    }
+}
+
+/// Call data which has already been encoded and validated.
+struct EncodedCall<'a>(&'a [u8]);
+
+impl Payload for EncodedCall<'_> {
+    fn encode_call_data_to(
+        &self,
+        _metadata: &Metadata,
+        out: &mut Vec<u8>,
+    ) -> Result<(), subxt_core::Error> {
+        out.extend_from_slice(self.0);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        backend::rpc::MockRpcClient,
+        client::{OfflineClient, OnlineClient, RuntimeVersion},
+        config::substrate::{BlakeTwo256, Digest, SubstrateHeader},
+        dynamic::Value,
+        error::RpcError,
+        utils::H256,
+        SubstrateConfig,
+    };
+    use codec::Decode;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    fn metadata() -> Metadata {
+        let metadata_bytes = include_bytes!("../../../artifacts/polkadot_metadata_small.scale");
+        Metadata::decode(&mut &metadata_bytes[..]).unwrap()
+    }
+
+    fn runtime_version() -> RuntimeVersion {
+        RuntimeVersion {
+            spec_version: 1,
+            transaction_version: 1,
+        }
+    }
+
+    fn remark(n: u8) -> impl Payload {
+        crate::dynamic::tx("System", "remark", vec![Value::from_bytes([n])])
+    }
+
+    #[test]
+    fn operational_txs_are_submitted_first_in_the_order_added() {
+        let client =
+            OfflineClient::<SubstrateConfig>::new(H256::zero(), runtime_version(), metadata());
+        let tx_client = client.tx();
+        let mut queue = tx_client.queue();
+
+        let pushed = [
+            (1, TxPriority::Normal),
+            (2, TxPriority::Operational),
+            (3, TxPriority::Normal),
+            (4, TxPriority::Operational),
+            (5, TxPriority::Normal),
+        ];
+        for (n, priority) in pushed {
+            queue.push_default(&remark(n), priority).unwrap();
+        }
+        assert_eq!(queue.len(), 5);
+
+        queue.sort_into_submission_order();
+
+        let expected: Vec<Vec<u8>> = [2, 4, 1, 3, 5]
+            .into_iter()
+            .map(|n| tx_client.call_data(&remark(n)).unwrap())
+            .collect();
+        let actual: Vec<Vec<u8>> = queue.queued.iter().map(|tx| tx.call_data.clone()).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn failed_submission_leaves_txs_queued() {
+        let fail_submission = Arc::new(AtomicBool::new(true));
+        let fail_submission2 = fail_submission.clone();
+
+        let mock_client = MockRpcClient::builder()
+            .method_handler("chain_getFinalizedHead", |_params| Ok(H256::zero()))
+            .method_handler("chain_getHeader", |_params| {
+                Ok(SubstrateHeader::<u32, BlakeTwo256> {
+                    parent_hash: H256::zero(),
+                    number: 1,
+                    state_root: H256::zero(),
+                    extrinsics_root: H256::zero(),
+                    digest: Digest::default(),
+                })
+            })
+            .method_handler("state_call", |_params| Ok("0x0000000000000000"))
+            .subscription_handler("author_submitAndWatchExtrinsic", move |_params| {
+                if fail_submission2.load(Ordering::SeqCst) {
+                    Err(RpcError::RequestRejected("pool is full".to_owned()))
+                } else {
+                    Ok([serde_json::json!("ready")])
+                }
+            })
+            .build();
+
+        let api = OnlineClient::<SubstrateConfig>::from_rpc_client_with(
+            H256::zero(),
+            runtime_version(),
+            metadata(),
+            mock_client,
+        )
+        .unwrap();
+
+        let signer = subxt_signer::sr25519::dev::alice();
+        let mut queue = api.tx().queue();
+        queue.push_default(&remark(1), TxPriority::Normal).unwrap();
+        queue
+            .push_default(&remark(2), TxPriority::Operational)
+            .unwrap();
+
+        // Nothing is lost if the first submission fails:
+        assert!(queue.sign_and_submit_then_watch(&signer).await.is_err());
+        assert_eq!(queue.len(), 2);
+
+        // And so everything can be submitted on a retry:
+        fail_submission.store(false, Ordering::SeqCst);
+        let progress = queue.sign_and_submit_then_watch(&signer).await.unwrap();
+        assert_eq!(progress.len(), 2);
+        assert!(queue.is_empty());
+    }
+}
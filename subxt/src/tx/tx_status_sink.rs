@@ -0,0 +1,29 @@
+// Copyright 2019-2023 Parity Technologies (UK) Ltd.
+// This file is dual-licensed as Apache-2.0 or GPL-3.0.
+// see LICENSE for license details.
+
+use crate::{backend::TransactionStatus, Config};
+
+/// Something which is told about every status update of every transaction that is submitted
+/// via a client that it's been registered with. See [`crate::OnlineClient::set_tx_status_sink()`].
+///
+/// This is called as each status is received, and so implementations should not block. To
+/// forward status updates somewhere else (for instance a message queue or a webhook), send
+/// them down a channel and handle them in a separate task.
+///
+/// This is implemented for any `Fn(T::Hash, &TransactionStatus<T::Hash>)`.
+pub trait TxStatusSink<T: Config>: Send + Sync + 'static {
+    /// Called with the hash of the extrinsic and its new status. The status contains the
+    /// hash of the block that the extrinsic is in, where relevant.
+    fn on_status(&self, ext_hash: T::Hash, status: &TransactionStatus<T::Hash>);
+}
+
+impl<T, F> TxStatusSink<T> for F
+where
+    T: Config,
+    F: Fn(T::Hash, &TransactionStatus<T::Hash>) + Send + Sync + 'static,
+{
+    fn on_status(&self, ext_hash: T::Hash, status: &TransactionStatus<T::Hash>) {
+        self(ext_hash, status)
+    }
+}
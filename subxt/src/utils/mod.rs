@@ -9,9 +9,9 @@ use crate::{error::RpcError, Error};
 use url::Url;
 
 pub use subxt_core::utils::{
-    bits, strip_compact_prefix, to_hex, AccountId32, Encoded, Era, KeyedVec, MultiAddress,
-    MultiSignature, PhantomDataSendSync, Static, UncheckedExtrinsic, WrapperKeepOpaque, Yes, H160,
-    H256, H512,
+    bits, strip_compact_prefix, to_hex, AccountId32, AddressFormat, Encoded, Era, FromSs58Error,
+    KeyedVec, MultiAddress, MultiSignature, PhantomDataSendSync, Ss58ParseMode, Static,
+    UncheckedExtrinsic, WrapperKeepOpaque, Yes, H160, H256, H512,
 };
 
 cfg_jsonrpsee! {
@@ -245,6 +245,47 @@ async fn fetch_block_and_decode_extrinsic_details() {
     assert!(tx.is_signed());
 }
 
+#[cfg(fullclient)]
+#[subxt_test]
+async fn decode_nested_calls_from_blocks() {
+    use node_runtime::runtime_types::frame_system::pallet::Call as SystemCall;
+
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let remark = |bytes: Vec<u8>| node_runtime::Call::System(SystemCall::remark { remark: bytes });
+    let tx = node_runtime::tx()
+        .utility()
+        .batch(vec![remark(vec![1]), remark(vec![2])]);
+
+    let in_block = api
+        .tx()
+        .sign_and_submit_then_watch_default(&tx, &dev::alice())
+        .await
+        .unwrap()
+        .wait_for_finalized()
+        .await
+        .unwrap();
+
+    let block = api.blocks().at(in_block.block_hash()).await.unwrap();
+    let extrinsics = block.extrinsics().await.unwrap();
+    let batch = extrinsics
+        .iter()
+        .map(|ext| ext.unwrap())
+        .find(|ext| ext.pallet_name().unwrap() == "Utility")
+        .unwrap();
+
+    let tree = batch.call_tree().unwrap();
+    assert_eq!(tree.pallet_name, "Utility");
+    assert_eq!(tree.call_name, "batch");
+    assert_eq!(tree.inner_calls.len(), 2);
+    for inner in &tree.inner_calls {
+        assert_eq!(inner.pallet_name, "System");
+        assert_eq!(inner.call_name, "remark");
+        assert!(inner.inner_calls.is_empty());
+    }
+}
+
 #[cfg(fullclient)]
 #[subxt_test]
 async fn decode_signed_extensions_from_blocks() {
@@ -5,7 +5,19 @@
 //! Just sanity checking some of the legacy RPC methods to make
 //! sure they don't error out and can decode their results OK.
 
-use crate::{subxt_test, test_context};
+use crate::{subxt_test, test_context, utils::node_runtime};
+use subxt::{
+    backend::legacy::{
+        rpc_methods::{ChildStorageKey, DispatchClass, ExtrinsicOrHash, OffchainStorageKind},
+        LegacyRpcMethods,
+    },
+    backend::rpc::rpc_params,
+    config::DefaultExtrinsicParamsBuilder,
+    error::{Error, RpcError},
+    utils::H256,
+    OnlineClient, SubstrateConfig,
+};
+use subxt_signer::sr25519::dev;
 
 #[subxt_test]
 async fn chain_get_block_hash() {
@@ -94,6 +106,14 @@ async fn system_health() {
     let _ = rpc.system_health().await.unwrap();
 }
 
+#[subxt_test]
+async fn system_peers() {
+    let ctx = test_context().await;
+    let rpc = ctx.legacy_rpc_methods().await;
+
+    let _ = rpc.system_peers().await.unwrap();
+}
+
 #[subxt_test]
 async fn system_chain() {
     let ctx = test_context().await;
@@ -125,3 +145,264 @@ async fn system_properties() {
 
     let _ = rpc.system_properties().await.unwrap();
 }
+
+#[subxt_test]
+async fn system_sync_state() {
+    let ctx = test_context().await;
+    let rpc = ctx.legacy_rpc_methods().await;
+
+    let _ = rpc.system_sync_state().await.unwrap();
+
+    // The dev node isn't expected to have peers, and so is synced straight away:
+    let sync_state = rpc.wait_until_synced().await.unwrap();
+    let health = rpc.system_health().await.unwrap();
+    assert!(sync_state.is_synced(&health));
+}
+
+#[subxt_test]
+async fn state_subscribe_storage() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+    let rpc = ctx.legacy_rpc_methods().await;
+
+    let addr = node_runtime::storage().system().number();
+    let key = api.storage().address_bytes(&addr).unwrap();
+
+    let mut sub = rpc.state_subscribe_storage([&*key]).await.unwrap();
+    let change_set = sub.next().await.unwrap().unwrap();
+    assert!(change_set.changes.iter().all(|(k, _)| k.0 == key));
+}
+
+#[subxt_test]
+async fn author_pending_extrinsics() {
+    let ctx = test_context().await;
+    let rpc = ctx.legacy_rpc_methods().await;
+
+    let _ = rpc.author_pending_extrinsics().await.unwrap();
+}
+
+#[subxt_test]
+async fn author_remove_extrinsic() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+    let rpc = ctx.legacy_rpc_methods().await;
+
+    // A nonce far in the future keeps the extrinsic waiting in the pool:
+    let tx = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let params = DefaultExtrinsicParamsBuilder::new()
+        .nonce(1_000_000)
+        .build();
+    let signed = api
+        .tx()
+        .create_signed(&tx, &dev::alice(), params)
+        .await
+        .unwrap();
+    let hash = rpc.author_submit_extrinsic(signed.encoded()).await.unwrap();
+
+    let pending = rpc.author_pending_extrinsics().await.unwrap();
+    assert!(pending.iter().any(|xt| xt == signed.encoded()));
+
+    let removed = rpc
+        .author_remove_extrinsic(vec![ExtrinsicOrHash::Hash(hash)])
+        .await
+        .unwrap();
+    assert_eq!(removed, vec![hash]);
+
+    let pending = rpc.author_pending_extrinsics().await.unwrap();
+    assert!(!pending.iter().any(|xt| xt == signed.encoded()));
+}
+
+#[subxt_test]
+async fn grandpa_prove_finality() {
+    let ctx = test_context().await;
+    let rpc = ctx.legacy_rpc_methods().await;
+
+    // The node may or may not be able to prove this yet, but the call should succeed:
+    let _ = rpc.grandpa_prove_finality(1u32.into()).await.unwrap();
+}
+
+#[subxt_test]
+async fn childstate_get_storage() {
+    let ctx = test_context().await;
+    let rpc = ctx.legacy_rpc_methods().await;
+
+    // Nothing exists in a child trie which has never been written to:
+    let child_key = ChildStorageKey::default_child_trie(b"not_a_child_trie");
+    let value = rpc
+        .childstate_get_storage(&child_key, b"key", None)
+        .await
+        .unwrap();
+    assert_eq!(value, None);
+
+    let keys = rpc
+        .childstate_get_keys_paged(&child_key, &[], 10, None, None)
+        .await
+        .unwrap();
+    assert!(keys.is_empty());
+}
+
+#[subxt_test]
+async fn offchain_local_storage() {
+    let ctx = test_context().await;
+    let rpc = ctx.legacy_rpc_methods().await;
+
+    let kind = OffchainStorageKind::Persistent;
+    rpc.offchain_local_storage_set(kind, b"subxt_test_key", b"value")
+        .await
+        .unwrap();
+    let value = rpc
+        .offchain_local_storage_get(kind, b"subxt_test_key")
+        .await
+        .unwrap();
+    assert_eq!(value.as_deref(), Some(&b"value"[..]));
+}
+
+#[subxt_test]
+async fn babe_epoch_authorship() {
+    let ctx = test_context().await;
+    let rpc = ctx.legacy_rpc_methods().await;
+
+    let _ = rpc.babe_epoch_authorship().await.unwrap();
+}
+
+#[subxt_test]
+async fn sync_state_gen_sync_spec() {
+    let ctx = test_context().await;
+    let rpc = ctx.legacy_rpc_methods().await;
+
+    let spec = rpc.sync_state_gen_sync_spec(false).await.unwrap();
+    assert!(spec.get("lightSyncState").is_some());
+}
+
+#[subxt_test]
+async fn http_rpc_client() {
+    let ctx = test_context().await;
+    let rpc = LegacyRpcMethods::<SubstrateConfig>::new(ctx.http_rpc_client().await);
+
+    // Requests work as normal over HTTP:
+    rpc.chain_get_block_hash(None).await.unwrap();
+
+    // But subscriptions aren't possible:
+    let sub = rpc.chain_subscribe_new_heads().await;
+    assert!(matches!(
+        sub.err(),
+        Some(Error::Rpc(RpcError::SubscriptionsNotSupported))
+    ));
+}
+
+#[subxt_test]
+async fn http_rpc_client_can_submit_transactions() {
+    let ctx = test_context().await;
+    let rpc = ctx.legacy_rpc_methods().await;
+    let api = OnlineClient::<SubstrateConfig>::from_rpc_client(ctx.http_rpc_client().await)
+        .await
+        .unwrap();
+
+    // A nonce far in the future keeps the extrinsic waiting in the pool:
+    let tx = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let params = DefaultExtrinsicParamsBuilder::new()
+        .nonce(1_000_000)
+        .build();
+    let signed = api
+        .tx()
+        .create_signed(&tx, &dev::alice(), params)
+        .await
+        .unwrap();
+
+    // We can't watch the transaction over HTTP, but we can submit it:
+    let hash = signed.submit().await.unwrap();
+    assert_eq!(hash, signed.hash());
+
+    let pending = rpc.author_pending_extrinsics().await.unwrap();
+    assert!(pending.iter().any(|xt| xt == signed.encoded()));
+}
+
+#[subxt_test]
+async fn chain_get_block_hashes() {
+    let ctx = test_context().await;
+    let rpc = ctx.legacy_rpc_methods().await;
+
+    let hashes = rpc
+        .chain_get_block_hashes([0u64.into(), u32::MAX.into()])
+        .await
+        .unwrap();
+
+    assert_eq!(hashes, vec![Some(rpc.genesis_hash().await.unwrap()), None]);
+}
+
+#[subxt_test]
+async fn batch_requests() {
+    let ctx = test_context().await;
+
+    for rpc_client in [ctx.rpc_client().await, ctx.http_rpc_client().await] {
+        let rpc = LegacyRpcMethods::<SubstrateConfig>::new(rpc_client.clone());
+        let mut expected = Vec::new();
+        for n in 0..3u32 {
+            expected.push(rpc.chain_get_block_hash(Some(n.into())).await.unwrap());
+        }
+
+        // Batched results come back in the same order as the requests:
+        let hashes: Vec<Result<Option<H256>, Error>> = rpc_client
+            .batch_request((0..3u32).map(|n| ("chain_getBlockHash", rpc_params![n])))
+            .await
+            .unwrap();
+        let hashes: Vec<_> = hashes.into_iter().map(|h| h.unwrap()).collect();
+        assert_eq!(hashes, expected);
+
+        // A failing request doesn't fail the others:
+        let res: Vec<Result<String, Error>> = rpc_client
+            .batch_request([
+                ("system_name", rpc_params![]),
+                ("not_a_method", rpc_params![]),
+            ])
+            .await
+            .unwrap();
+        assert!(res[0].is_ok());
+        assert!(res[1].is_err());
+    }
+}
+
+#[subxt_test]
+async fn payment_query_info() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+    let rpc = ctx.legacy_rpc_methods().await;
+
+    let tx = node_runtime::tx()
+        .balances()
+        .transfer_allow_death(dev::bob().public_key().into(), 10_000);
+    let signed = api
+        .tx()
+        .create_signed(&tx, &dev::alice(), Default::default())
+        .await
+        .unwrap();
+
+    let info = rpc
+        .payment_query_info(signed.encoded(), None)
+        .await
+        .unwrap();
+    assert_eq!(info.class, DispatchClass::Normal);
+    assert!(info.partial_fee > 0);
+}
+
+#[subxt_test]
+async fn payment_query_fee_details() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+    let rpc = ctx.legacy_rpc_methods().await;
+
+    let tx = node_runtime::tx()
+        .balances()
+        .transfer_allow_death(dev::bob().public_key().into(), 10_000);
+    let signed = api
+        .tx()
+        .create_signed(&tx, &dev::alice(), Default::default())
+        .await
+        .unwrap();
+
+    let details = rpc
+        .payment_query_fee_details(signed.encoded(), None)
+        .await
+        .unwrap();
+    assert!(details.inclusion_fee.is_some());
+}
@@ -409,3 +409,14 @@ async fn partial_fee_estimate_correct() {
     // Both methods should yield the same fee
     assert_eq!(partial_fee_1, partial_fee_2);
 }
+
+#[subxt_test]
+async fn metadata_at_block() {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    // The runtime hasn't been upgraded, so the metadata at genesis
+    // is the same as the metadata that the client is using.
+    let metadata = api.metadata_at(api.genesis_hash()).await.unwrap();
+    assert_eq!(metadata.hasher().hash(), api.metadata().hasher().hash());
+}
@@ -8,7 +8,7 @@ use crate::{
 };
 use codec::Decode;
 use subxt::{
-    error::{DispatchError, Error, TokenError},
+    error::{DispatchError, Error, TokenError, TransactionError},
     utils::{AccountId32, MultiAddress},
 };
 use subxt_signer::sr25519::dev;
@@ -382,6 +382,43 @@ async fn transfer_error() {
     );
 }
 
+#[subxt_test]
+async fn transfer_preview_and_confirm() -> Result<(), subxt::Error> {
+    let alice = dev::alice();
+    let bob_address = dev::bob().public_key().into();
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let tx = node_runtime::tx()
+        .balances()
+        .transfer_allow_death(bob_address, 10_000);
+
+    let preview = api.tx().preview(&tx).await?;
+    assert_eq!(preview.pallet_name(), "Balances");
+    assert_eq!(preview.call_name(), "transfer_allow_death");
+    assert!(*preview.partial_fee_estimate() > 0);
+
+    // Nothing is signed or submitted if the preview isn't confirmed:
+    let res = api
+        .tx()
+        .sign_and_submit_then_watch_confirmed(&tx, &alice, Default::default(), |_| false)
+        .await;
+    assert!(
+        matches!(res, Err(Error::Transaction(TransactionError::NotConfirmed))),
+        "Expected the transaction not to be confirmed"
+    );
+
+    api.tx()
+        .sign_and_submit_then_watch_confirmed(&tx, &alice, Default::default(), |p| {
+            p.call_name() == "transfer_allow_death" && *p.partial_fee_estimate() > 0
+        })
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    Ok(())
+}
+
 #[subxt_test]
 async fn transfer_implicit_subscription() {
     let alice = dev::alice();
@@ -440,3 +477,23 @@ async fn constant_existential_deposit() {
     // Make sure thetwo are identical:
     assert_eq!(existential_deposit, api.constants().at(&addr).unwrap());
 }
+
+#[subxt_test]
+async fn fund_accounts_from_dev_account() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let account = AccountId32([7; 32]);
+    let amount = 1_000_000_000_000_000_000;
+    ctx.fund_accounts([(account.clone(), amount)]).await?;
+
+    let account_info = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch_or_default(&node_runtime::storage().system().account(account))
+        .await?;
+
+    assert_eq!(account_info.data.free, amount);
+    Ok(())
+}
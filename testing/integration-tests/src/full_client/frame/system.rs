@@ -7,6 +7,7 @@ use crate::{
     subxt_test, test_context,
 };
 use assert_matches::assert_matches;
+use subxt::tx::TxPriority;
 use subxt_signer::sr25519::dev;
 
 #[subxt_test]
@@ -57,3 +58,68 @@ async fn tx_remark_with_event() -> Result<(), subxt::Error> {
     assert!(found_event);
     Ok(())
 }
+
+#[subxt_test]
+async fn tx_exceeding_block_length_is_rejected_before_submission() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = dev::alice();
+
+    // Substrate's default max block length is 5MiB; this remark won't fit.
+    let tx = node_runtime::tx()
+        .system()
+        .remark(vec![0u8; 6 * 1024 * 1024]);
+
+    let signed_extrinsic = api
+        .tx()
+        .create_signed(&tx, &alice, Default::default())
+        .await?;
+
+    assert_matches!(
+        signed_extrinsic.submit().await,
+        Err(subxt::Error::Transaction(
+            subxt::error::TransactionError::ExceedsBlockLength { .. }
+        ))
+    );
+    Ok(())
+}
+
+#[subxt_test]
+async fn tx_queue_submits_every_queued_tx() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let alice = dev::alice();
+    let nonce_before = api
+        .tx()
+        .account_nonce(&alice.public_key().to_account_id())
+        .await?;
+
+    let mut queue = api.tx().queue();
+    let bulk = node_runtime::tx().system().remark(b"bulk".to_vec());
+    let urgent = node_runtime::tx()
+        .system()
+        .remark_with_event(b"urgent".to_vec());
+    queue.push_default(&bulk, TxPriority::Normal)?;
+    queue.push_default(&urgent, TxPriority::Operational)?;
+    assert_eq!(queue.len(), 2);
+
+    let mut progress = queue.sign_and_submit_then_watch(&alice).await?;
+    assert!(queue.is_empty());
+    assert_eq!(progress.len(), 2);
+
+    // The operational transaction is submitted first:
+    let bulk_progress = progress.pop().unwrap();
+    let urgent_progress = progress.pop().unwrap();
+    let urgent_events = urgent_progress.wait_for_finalized_success().await?;
+    assert!(urgent_events.has::<system::events::Remarked>()?);
+    bulk_progress.wait_for_finalized_success().await?;
+
+    let nonce_after = api
+        .tx()
+        .account_nonce(&alice.public_key().to_account_id())
+        .await?;
+    assert_eq!(nonce_after, nonce_before + 2);
+    Ok(())
+}
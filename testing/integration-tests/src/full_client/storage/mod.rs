@@ -286,3 +286,34 @@ async fn storage_iter_decode_keys() -> Result<(), subxt::Error> {
 
     Ok(())
 }
+
+#[cfg(fullclient)]
+#[subxt_test]
+async fn storage_diff_across_blocks() -> Result<(), subxt::Error> {
+    let ctx = test_context().await;
+    let api = ctx.client();
+
+    let signer = dev::alice();
+    let alice: AccountId32 = dev::alice().public_key().into();
+    let before = api.blocks().at_latest().await?.reference();
+
+    // Bump the Alice nonce by submitting a remark:
+    let remark_tx = node_runtime::tx().system().remark(vec![1, 2, 3]);
+    let in_block = api
+        .tx()
+        .sign_and_submit_then_watch_default(&remark_tx, &signer)
+        .await?
+        .wait_for_finalized()
+        .await?;
+    let after = in_block.block_hash();
+    in_block.wait_for_success().await?;
+
+    let addr = node_runtime::storage().system().account(alice);
+    let diff = api.storage().diff(&addr, before, after).await?;
+
+    let nonce_before = diff.before().map(|info| info.nonce).unwrap_or(0);
+    assert_eq!(diff.after().unwrap().nonce, nonce_before + 1);
+    assert!(diff.changes().iter().any(|change| change.path == ".nonce"));
+
+    Ok(())
+}
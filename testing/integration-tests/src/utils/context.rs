@@ -5,7 +5,9 @@
 pub(crate) use crate::{node_runtime, utils::TestNodeProcess};
 
 use subxt::client::OnlineClient;
+use subxt::utils::AccountId32;
 use subxt::SubstrateConfig;
+use subxt_signer::sr25519::dev;
 
 /// `substrate-node` should be installed on the $PATH. We fall back
 /// to also checking for an older `substrate` binary.
@@ -30,3 +32,27 @@ pub type TestClient = OnlineClient<SubstrateConfig>;
 pub async fn test_context() -> TestContext {
     test_context_with("alice".to_string()).await
 }
+
+impl TestContext {
+    /// Fund each of the given accounts with the corresponding amount, by transferring it
+    /// from the pre-funded `//Alice` dev account. Returns once every transfer is finalized.
+    pub async fn fund_accounts(
+        &self,
+        accounts: impl IntoIterator<Item = (AccountId32, u128)>,
+    ) -> Result<(), subxt::Error> {
+        let alice = dev::alice();
+        for (account, amount) in accounts {
+            let tx = node_runtime::tx()
+                .balances()
+                .transfer_allow_death(account.into(), amount);
+
+            self.client()
+                .tx()
+                .sign_and_submit_then_watch_default(&tx, &alice)
+                .await?
+                .wait_for_finalized_success()
+                .await?;
+        }
+        Ok(())
+    }
+}
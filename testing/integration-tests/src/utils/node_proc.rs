@@ -56,6 +56,14 @@ where
             .expect("Unable to connect RPC client to test node")
     }
 
+    /// Hand back an RPC client which talks to the test node over HTTP.
+    pub async fn http_rpc_client(&self) -> rpc::RpcClient {
+        let url = format!("http://127.0.0.1:{}", self.proc.ws_port());
+        rpc::RpcClient::from_insecure_url(url)
+            .await
+            .expect("Unable to connect HTTP RPC client to test node")
+    }
+
     /// Always return a client using the unstable backend.
     /// Only use for comparing backends; use [`TestNodeProcess::client()`] normally,
     /// which enables us to run each test against both backends.